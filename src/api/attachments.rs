@@ -0,0 +1,130 @@
+use crate::api::client::{ClickUpApi, ensure_success, parse_json_ok};
+use crate::error::AppError;
+use gpui::{Image, ImageFormat};
+use reqwest::blocking::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default, alias = "title", alias = "file_name", alias = "filename")]
+    pub name: Option<String>,
+    pub url: String,
+    #[serde(default, alias = "mimetype", alias = "mime_type", alias = "type")]
+    pub content_type: Option<String>,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(
+        default,
+        alias = "thumbnail_large",
+        alias = "thumbnail_medium",
+        alias = "thumbnail_small"
+    )]
+    pub thumbnail_url: Option<String>,
+}
+
+impl MessageAttachment {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .filter(|name| !name.is_empty())
+            .or_else(|| {
+                self.url
+                    .split('?')
+                    .next()
+                    .and_then(|path| path.rsplit('/').next())
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| "Attachment".to_string())
+    }
+
+    pub fn image_format(&self) -> Option<ImageFormat> {
+        if let Some(content_type) = self.content_type.as_deref()
+            && let Some(subtype) = content_type.strip_prefix("image/")
+        {
+            return image_format_for(subtype);
+        }
+
+        let name = self.display_name();
+        let extension = name.rsplit_once('.')?.1;
+        image_format_for(extension)
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.image_format().is_some()
+    }
+
+    pub fn preview_url(&self) -> &str {
+        self.thumbnail_url.as_deref().unwrap_or(&self.url)
+    }
+}
+
+fn image_format_for(extension: &str) -> Option<ImageFormat> {
+    match extension.to_ascii_lowercase().as_str() {
+        "png" => Some(ImageFormat::Png),
+        "jpg" | "jpeg" => Some(ImageFormat::Jpeg),
+        "gif" => Some(ImageFormat::Gif),
+        "webp" => Some(ImageFormat::Webp),
+        "bmp" => Some(ImageFormat::Bmp),
+        "svg" | "svg+xml" => Some(ImageFormat::Svg),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum UploadAttachmentResponse {
+    Wrapped { data: MessageAttachment },
+    Plain(MessageAttachment),
+}
+
+impl ClickUpApi {
+    pub fn upload_attachment(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        path: &Path,
+    ) -> Result<MessageAttachment, AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/attachments",
+            self.base_v3_url
+        );
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "attachment".to_string());
+        let bytes = std::fs::read(path)?;
+        let part = Part::bytes(bytes).file_name(file_name.clone());
+        let form = Form::new().part("attachment", part);
+
+        let response = self.execute(self.request_multipart(url, form)?)?;
+        let mut attachment = match parse_json_ok(response)? {
+            UploadAttachmentResponse::Wrapped { data } => data,
+            UploadAttachmentResponse::Plain(attachment) => attachment,
+        };
+        if attachment.name.is_none() {
+            attachment.name = Some(file_name);
+        }
+        Ok(attachment)
+    }
+
+    pub fn download_attachment(&self, url: &str) -> Result<Vec<u8>, AppError> {
+        let response = ensure_success(self.client.get(url).send()?)?;
+        Ok(response.bytes()?.to_vec())
+    }
+
+    pub fn fetch_attachment_image(
+        &self,
+        attachment: &MessageAttachment,
+    ) -> Result<Arc<Image>, AppError> {
+        let format = attachment
+            .image_format()
+            .ok_or_else(|| AppError::Parse("Attachment is not an image".to_string()))?;
+        let bytes = self.download_attachment(attachment.preview_url())?;
+        Ok(Arc::new(Image::from_bytes(format, bytes)))
+    }
+}
@@ -1,10 +1,33 @@
-use crate::api::client::{ClickUpApi, ensure_success, parse_json_ok};
+use crate::api::attachments::MessageAttachment;
+use crate::api::client::{ClickUpApi, api_error, ensure_success, parse_json_ok};
 use crate::error::AppError;
+use regex::Regex;
+use reqwest::Url;
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
 
-#[derive(Clone, Debug, Deserialize)]
+const CHANNELS_PAGE_SIZE: usize = 50;
+const MAX_CHANNEL_PAGES: usize = 100;
+pub const MESSAGES_PAGE_SIZE: usize = 50;
+const REACTION_EMOJIS: [(&str, &str); 10] = [
+    ("thumbsup", "👍"),
+    ("thumbsdown", "👎"),
+    ("heart", "❤️"),
+    ("joy", "😂"),
+    ("tada", "🎉"),
+    ("open_mouth", "😮"),
+    ("cry", "😢"),
+    ("eyes", "👀"),
+    ("white_check_mark", "✅"),
+    ("fire", "🔥"),
+];
+
+pub static MENTION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[@?([^\]]+)\]\(#user_mention#(\d+)\)").expect("Invalid regex"));
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ClickUpChatChannel {
     pub id: String,
     #[serde(default)]
@@ -41,7 +64,7 @@ impl ClickUpChatChannel {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChannelMember {
     pub id: String,
     #[serde(default)]
@@ -50,9 +73,33 @@ pub struct ChannelMember {
     pub email: Option<String>,
     #[serde(rename = "profilePicture", default)]
     pub profile_picture: Option<String>,
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+impl ChannelMember {
+    pub fn display_name(&self) -> String {
+        self.username
+            .clone()
+            .filter(|name| !name.is_empty())
+            .or_else(|| self.email.clone())
+            .unwrap_or_else(|| self.id.clone())
+    }
+
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        [self.username.as_deref(), self.email.as_deref()]
+            .into_iter()
+            .flatten()
+            .any(|value| value.to_lowercase().contains(&query))
+    }
+
+    pub fn mention_markup(&self) -> String {
+        format!("[@{}](#user_mention#{})", self.display_name(), self.id)
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ChatMessage {
     pub id: String,
     text: Option<String>,
@@ -61,13 +108,35 @@ pub struct ChatMessage {
     pub date_updated: Option<u64>,
     pub creator: Option<MessageCreator>,
     pub date_created: Option<String>,
+    pub reactions: Vec<MessageReaction>,
+    pub replies_count: u64,
+    pub attachments: Vec<MessageAttachment>,
     pub pending: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageReaction {
+    pub reaction: String,
+    #[serde(
+        default,
+        alias = "userId",
+        deserialize_with = "deserialize_opt_string_or_number"
+    )]
+    pub user_id: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReactionSummary {
+    pub reaction: String,
+    pub count: usize,
+    pub reacted: bool,
+}
+
 impl ChatMessage {
     pub fn new_pending(
         temp_id: String,
         content: String,
+        attachments: Vec<MessageAttachment>,
         user_id: String,
         username: String,
     ) -> Self {
@@ -84,10 +153,33 @@ impl ChatMessage {
                 profile_picture: None,
             }),
             date_created: None,
+            reactions: Vec::new(),
+            replies_count: 0,
+            attachments,
             pending: true,
         }
     }
 
+    pub fn has_text(&self) -> bool {
+        self.text
+            .as_deref()
+            .is_some_and(|text| !is_effectively_empty(text))
+    }
+
+    pub fn mentions_user(&self, user_id: &str) -> bool {
+        self.text.as_deref().is_some_and(|text| {
+            MENTION_REGEX
+                .captures_iter(text)
+                .any(|caps| &caps[2] == user_id)
+        })
+    }
+
+    pub fn preview_text(&self) -> String {
+        MENTION_REGEX
+            .replace_all(&self.display_content(), "@$1")
+            .into_owned()
+    }
+
     pub fn display_content(&self) -> String {
         match self.text.as_deref() {
             None => "[No content]".to_string(),
@@ -96,6 +188,59 @@ impl ChatMessage {
         }
     }
 
+    pub fn content(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn is_edited(&self) -> bool {
+        match (self.date, self.date_updated) {
+            (Some(date), Some(updated)) => updated != date,
+            _ => false,
+        }
+    }
+
+    pub fn has_reaction(&self, reaction: &str, user_id: &str) -> bool {
+        let reaction = canonical_reaction(reaction);
+        self.reactions
+            .iter()
+            .any(|r| r.reaction == reaction && r.user_id.as_deref() == Some(user_id))
+    }
+
+    pub fn add_reaction(&mut self, reaction: &str, user_id: &str) {
+        if !self.has_reaction(reaction, user_id) {
+            self.reactions.push(MessageReaction {
+                reaction: canonical_reaction(reaction).to_string(),
+                user_id: Some(user_id.to_string()),
+            });
+        }
+    }
+
+    pub fn remove_reaction(&mut self, reaction: &str, user_id: &str) {
+        let reaction = canonical_reaction(reaction);
+        self.reactions
+            .retain(|r| !(r.reaction == reaction && r.user_id.as_deref() == Some(user_id)));
+    }
+
+    pub fn reaction_summary(&self, current_user_id: Option<&str>) -> Vec<ReactionSummary> {
+        let mut summary: Vec<ReactionSummary> = Vec::new();
+        for reaction in &self.reactions {
+            let reacted =
+                current_user_id.is_some() && reaction.user_id.as_deref() == current_user_id;
+            match summary.iter_mut().find(|s| s.reaction == reaction.reaction) {
+                Some(existing) => {
+                    existing.count += 1;
+                    existing.reacted |= reacted;
+                }
+                None => summary.push(ReactionSummary {
+                    reaction: reaction.reaction.clone(),
+                    count: 1,
+                    reacted,
+                }),
+            }
+        }
+        summary
+    }
+
     pub fn creator_name(&self) -> String {
         self.creator
             .as_ref()
@@ -111,7 +256,7 @@ impl ChatMessage {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageCreator {
     pub id: String,
     #[serde(default)]
@@ -122,7 +267,7 @@ pub struct MessageCreator {
     pub profile_picture: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct ChatMessageWire {
     #[serde(deserialize_with = "deserialize_string_or_number")]
     id: String,
@@ -145,6 +290,16 @@ struct ChatMessageWire {
     creator: Option<MessageCreator>,
     #[serde(default)]
     date_created: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_lenient_list")]
+    reactions: Vec<MessageReaction>,
+    #[serde(default, alias = "replyCount", alias = "repliesCount")]
+    replies_count: Option<u64>,
+    #[serde(
+        default,
+        alias = "files",
+        deserialize_with = "deserialize_lenient_list"
+    )]
+    attachments: Vec<MessageAttachment>,
 }
 
 impl<'de> Deserialize<'de> for ChatMessage {
@@ -161,11 +316,42 @@ impl<'de> Deserialize<'de> for ChatMessage {
             date_updated: wire.date_updated,
             creator: wire.creator,
             date_created: wire.date_created,
+            reactions: wire
+                .reactions
+                .into_iter()
+                .map(|mut reaction| {
+                    reaction.reaction = canonical_reaction(&reaction.reaction).to_string();
+                    reaction
+                })
+                .collect(),
+            replies_count: wire.replies_count.unwrap_or(0),
+            attachments: wire.attachments,
             pending: false,
         })
     }
 }
 
+impl Serialize for ChatMessage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ChatMessageWire {
+            id: self.id.clone(),
+            content: self.text.clone(),
+            user_id: self.user_id.clone(),
+            date: self.date,
+            date_updated: self.date_updated,
+            creator: self.creator.clone(),
+            date_created: self.date_created.clone(),
+            reactions: self.reactions.clone(),
+            replies_count: Some(self.replies_count),
+            attachments: self.attachments.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
 fn deserialize_opt_string_or_number<'de, Des>(
     deserializer: Des,
 ) -> Result<Option<String>, Des::Error>
@@ -182,6 +368,28 @@ where
     })
 }
 
+fn deserialize_lenient_list<'de, Des, T>(deserializer: Des) -> Result<Vec<T>, Des::Error>
+where
+    Des: Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let maybe_value = Option::<serde_json::Value>::deserialize(deserializer)?;
+
+    let items = match maybe_value {
+        Some(serde_json::Value::Array(items)) => items,
+        Some(serde_json::Value::Object(mut map)) => match map.remove("data") {
+            Some(serde_json::Value::Array(items)) => items,
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    };
+
+    Ok(items
+        .into_iter()
+        .filter_map(|item| serde_json::from_value(item).ok())
+        .collect())
+}
+
 fn deserialize_string_or_number<'de, Des>(deserializer: Des) -> Result<String, Des::Error>
 where
     Des: Deserializer<'de>,
@@ -195,6 +403,17 @@ where
     }
 }
 
+pub(super) fn deserialize_u64_from_string_or_number<'de, Des>(
+    deserializer: Des,
+) -> Result<u64, Des::Error>
+where
+    Des: Deserializer<'de>,
+{
+    deserialize_string_or_number(deserializer)?
+        .parse::<u64>()
+        .map_err(|_| serde::de::Error::custom("expected numeric id"))
+}
+
 fn is_effectively_empty(input: &str) -> bool {
     input
         .chars()
@@ -205,6 +424,14 @@ fn is_effectively_empty(input: &str) -> bool {
 struct GetChatChannelsResponse {
     #[serde(default)]
     data: Vec<ClickUpChatChannel>,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ChatChannelsPage {
+    pub channels: Vec<ClickUpChatChannel>,
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -217,53 +444,215 @@ struct GetChannelMembersResponse {
 struct GetMessagesResponse {
     #[serde(default)]
     data: Vec<ChatMessage>,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ChatMessagesPage {
+    pub messages: Vec<ChatMessage>,
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
-struct SendMessageRequest {
+struct SendMessageRequest<'a> {
+    content: &'a str,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    attachments: &'a [MessageAttachment],
+}
+
+#[derive(Debug, Serialize)]
+struct EditMessageRequest {
     content: String,
 }
 
+#[derive(Debug, Serialize)]
+struct CreateChannelRequest<'a> {
+    name: &'a str,
+    visibility: &'a str,
+    user_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateDirectMessageRequest {
+    user_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct AddChannelMembersRequest {
+    user_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ChannelResponse {
+    Wrapped { data: ClickUpChatChannel },
+    Plain(ClickUpChatChannel),
+}
+
+impl From<ChannelResponse> for ClickUpChatChannel {
+    fn from(response: ChannelResponse) -> Self {
+        match response {
+            ChannelResponse::Wrapped { data } => data,
+            ChannelResponse::Plain(channel) => channel,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ReactionRequest<'a> {
+    reaction: &'a str,
+}
+
+#[derive(Debug, Default)]
+pub struct PageCursors {
+    seen: HashSet<String>,
+    pages: usize,
+}
+
+impl PageCursors {
+    pub fn advance(&mut self, next: Option<String>, page_len: usize) -> Option<String> {
+        self.pages += 1;
+        let next = next.filter(|cursor| !cursor.is_empty())?;
+        if page_len == 0 || self.pages >= MAX_CHANNEL_PAGES || !self.seen.insert(next.clone()) {
+            return None;
+        }
+        Some(next)
+    }
+}
+
 impl ClickUpApi {
     pub fn get_chat_channels(
         &self,
         workspace_id: u64,
         current_user_id: Option<u64>,
     ) -> Result<Vec<ClickUpChatChannel>, AppError> {
-        let url = format!(
-            "{}/workspaces/{workspace_id}/chat/channels?limit=10&is_follower=true&include_closed=false",
-            self.base_v3_url
-        );
-        let response = self.request_get(url)?.send()?;
-        let body: GetChatChannelsResponse = parse_json_ok(response)?;
+        let mut channels = Vec::new();
+        let mut cursor: Option<String> = None;
+        let mut cursors = PageCursors::default();
+
+        loop {
+            let page =
+                self.get_chat_channels_page(workspace_id, current_user_id, cursor.as_deref())?;
+            let next = cursors.advance(page.next_cursor, page.channels.len());
+            channels.extend(page.channels);
+
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(channels)
+    }
+
+    pub fn get_chat_channels_page(
+        &self,
+        workspace_id: u64,
+        current_user_id: Option<u64>,
+        cursor: Option<&str>,
+    ) -> Result<ChatChannelsPage, AppError> {
+        let body = self.request_chat_channels_page(workspace_id, cursor, None)?;
 
         let mut channels = body.data;
         for channel in &mut channels {
-            if channel.channel_type == "DM"
-                && channel.name.is_none()
-                && let Ok(members) = self.get_channel_members(workspace_id, &channel.id)
-            {
-                let other_members: Vec<_> = members
-                    .iter()
-                    .filter(|m| {
-                        if let Some(current_id) = current_user_id {
-                            m.id.parse::<u64>().ok() != Some(current_id)
-                        } else {
-                            true
-                        }
-                    })
-                    .filter_map(|member| member.username.clone())
-                    .collect();
-
-                if !other_members.is_empty() {
-                    channel.name = Some(other_members.join(", "));
-                }
+            self.name_direct_message(workspace_id, current_user_id, channel);
+        }
+
+        Ok(ChatChannelsPage {
+            channels,
+            next_cursor: body.next_cursor.filter(|c| !c.is_empty()),
+        })
+    }
+
+    pub fn get_channel_activity(
+        &self,
+        workspace_id: u64,
+        current_user_id: Option<u64>,
+        known_ids: &HashSet<String>,
+        since: Option<u64>,
+    ) -> Result<Vec<ClickUpChatChannel>, AppError> {
+        let is_new = |channel: &ClickUpChatChannel| {
+            since.is_none_or(|since| channel.latest_comment_at.is_some_and(|at| at > since))
+        };
+
+        let mut channels = Vec::new();
+        let mut cursor: Option<String> = None;
+        let mut cursors = PageCursors::default();
+
+        loop {
+            let body = self.request_chat_channels_page(workspace_id, cursor.as_deref(), since)?;
+            let reached_seen = !body.data.iter().all(is_new);
+            let next = cursors.advance(body.next_cursor, body.data.len());
+            channels.extend(body.data.into_iter().filter(is_new));
+            match next {
+                Some(next) if !reached_seen => cursor = Some(next),
+                _ => break,
             }
         }
 
+        for channel in channels
+            .iter_mut()
+            .filter(|channel| !known_ids.contains(&channel.id))
+        {
+            self.name_direct_message(workspace_id, current_user_id, channel);
+        }
+
         Ok(channels)
     }
 
+    fn name_direct_message(
+        &self,
+        workspace_id: u64,
+        current_user_id: Option<u64>,
+        channel: &mut ClickUpChatChannel,
+    ) {
+        if channel.channel_type != "DM" || channel.name.is_some() {
+            return;
+        }
+        let Ok(members) = self.get_channel_members(workspace_id, &channel.id) else {
+            return;
+        };
+
+        let other_members: Vec<_> = members
+            .iter()
+            .filter(|m| {
+                if let Some(current_id) = current_user_id {
+                    m.id.parse::<u64>().ok() != Some(current_id)
+                } else {
+                    true
+                }
+            })
+            .filter_map(|member| member.username.clone())
+            .collect();
+
+        if !other_members.is_empty() {
+            channel.name = Some(other_members.join(", "));
+        }
+    }
+
+    fn request_chat_channels_page(
+        &self,
+        workspace_id: u64,
+        cursor: Option<&str>,
+        with_comment_since: Option<u64>,
+    ) -> Result<GetChatChannelsResponse, AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/channels?limit={CHANNELS_PAGE_SIZE}&is_follower=true&include_closed=false",
+            self.base_v3_url
+        );
+        let mut request = self.request_get(url)?;
+        if let Some(cursor) = cursor {
+            request = request.query(&[("cursor", cursor)]);
+        }
+        if let Some(since) = with_comment_since {
+            request = request.query(&[("with_comment_since", since)]);
+        }
+
+        let response = self.execute(request)?;
+        parse_json_ok(response)
+    }
+
     pub fn get_channel_members(
         &self,
         workspace_id: u64,
@@ -273,38 +662,236 @@ impl ClickUpApi {
             "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/members",
             self.base_v3_url
         );
-        let response = self.request_get(url)?.send()?;
+        let response = self.execute(self.request_get(url)?)?;
         let body: GetChannelMembersResponse = parse_json_ok(response)?;
         Ok(body.data)
     }
 
+    pub fn create_channel(
+        &self,
+        workspace_id: u64,
+        name: &str,
+        private: bool,
+        user_ids: &[u64],
+    ) -> Result<ClickUpChatChannel, AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/channels",
+            self.base_v3_url
+        );
+        let body = CreateChannelRequest {
+            name,
+            visibility: if private { "PRIVATE" } else { "PUBLIC" },
+            user_ids: user_ids.iter().map(u64::to_string).collect(),
+        };
+        let response = self.execute(self.request_post(url, &body)?)?;
+        let channel: ChannelResponse = parse_json_ok(response)?;
+        Ok(channel.into())
+    }
+
+    pub fn create_direct_message(
+        &self,
+        workspace_id: u64,
+        user_ids: &[u64],
+    ) -> Result<ClickUpChatChannel, AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/channels/direct_message",
+            self.base_v3_url
+        );
+        let body = CreateDirectMessageRequest {
+            user_ids: user_ids.iter().map(u64::to_string).collect(),
+        };
+        let response = self.execute(self.request_post(url, &body)?)?;
+        let channel: ChannelResponse = parse_json_ok(response)?;
+        Ok(channel.into())
+    }
+
+    pub fn add_channel_members(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        user_ids: &[u64],
+    ) -> Result<(), AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/members",
+            self.base_v3_url
+        );
+        let body = AddChannelMembersRequest {
+            user_ids: user_ids.iter().map(u64::to_string).collect(),
+        };
+        ensure_success(self.execute(self.request_post(url, &body)?)?)?;
+        Ok(())
+    }
+
+    pub fn remove_channel_member(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        user_id: &str,
+    ) -> Result<(), AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/members/{user_id}",
+            self.base_v3_url
+        );
+        ensure_success(self.execute(self.request_delete(url)?)?)?;
+        Ok(())
+    }
+
+    pub fn unfollow_channel(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        user_id: &str,
+    ) -> Result<(), AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/followers/{user_id}",
+            self.base_v3_url
+        );
+        ensure_success(self.execute(self.request_delete(url)?)?)?;
+        Ok(())
+    }
+
+    pub fn get_mention_candidates(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+    ) -> Result<Vec<ChannelMember>, AppError> {
+        match self.get_channel_members(workspace_id, channel_id) {
+            Ok(members) if !members.is_empty() => Ok(members),
+            Err(err @ AppError::RateLimited { .. }) => Err(err),
+            Err(err) if err.is_unauthorized() => Err(err),
+            _ => Ok(self
+                .get_team_members(workspace_id)?
+                .into_iter()
+                .map(|user| ChannelMember {
+                    id: user.id.to_string(),
+                    username: Some(user.username),
+                    email: Some(user.email),
+                    profile_picture: user.profile_picture_url,
+                    role: None,
+                })
+                .collect()),
+        }
+    }
+
     pub fn get_channel_messages(
         &self,
         workspace_id: u64,
         channel_id: &str,
     ) -> Result<Vec<ChatMessage>, AppError> {
+        self.get_channel_messages_page(workspace_id, channel_id, None)
+            .map(|page| page.messages)
+    }
+
+    pub fn get_channel_messages_page(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        cursor: Option<&str>,
+    ) -> Result<ChatMessagesPage, AppError> {
+        let mut page = self.request_channel_messages_page(workspace_id, channel_id, cursor)?;
+        self.enrich_message_creators(workspace_id, channel_id, &mut page.messages);
+
+        Ok(page)
+    }
+
+    pub fn get_channel_history(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+    ) -> Result<Vec<ChatMessage>, AppError> {
+        let mut messages = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let page =
+                self.request_channel_messages_page(workspace_id, channel_id, cursor.as_deref())?;
+            let page_empty = page.messages.is_empty();
+            messages.extend(page.messages);
+            match page.next_cursor {
+                Some(next) if !page_empty => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        messages.reverse();
+        Ok(messages)
+    }
+
+    pub fn request_channel_messages_page(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        cursor: Option<&str>,
+    ) -> Result<ChatMessagesPage, AppError> {
         let url = format!(
-            "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/messages?limit=50",
+            "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/messages?limit={MESSAGES_PAGE_SIZE}",
             self.base_v3_url
         );
-        let response = self.request_get(url)?.send()?;
-        let response = ensure_success(response)?;
-        let text = response
-            .text()
-            .map_err(|e| AppError::Parse(e.to_string()))?;
-
-        let mut messages = match serde_json::from_str::<GetMessagesResponse>(&text) {
-            Ok(body) => body.data,
-            Err(_) => match serde_json::from_str::<Vec<ChatMessage>>(&text) {
-                Ok(messages) => messages,
-                Err(_) => {
-                    return Err(AppError::Parse(
-                        "Failed to parse messages response".to_string(),
-                    ));
-                }
-            },
+        let mut request = self.request_get(url)?;
+        if let Some(cursor) = cursor {
+            request = request.query(&[("cursor", cursor)]);
+        }
+
+        let response = self.execute(request)?;
+        parse_messages_page(response)
+    }
+
+    pub fn get_message_replies(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        message_id: &str,
+    ) -> Result<Vec<ChatMessage>, AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/messages/{message_id}/replies?limit={MESSAGES_PAGE_SIZE}",
+            self.base_v3_url
+        );
+        let mut replies = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut request = self.request_get(url.clone())?;
+            if let Some(cursor) = cursor.as_deref() {
+                request = request.query(&[("cursor", cursor)]);
+            }
+
+            let page = parse_messages_page(self.execute(request)?)?;
+            replies.extend(page.messages);
+
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        self.enrich_message_creators(workspace_id, channel_id, &mut replies);
+        Ok(replies)
+    }
+
+    pub fn send_reply(
+        &self,
+        workspace_id: u64,
+        message_id: &str,
+        content: &str,
+    ) -> Result<ChatMessage, AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/messages/{message_id}/replies",
+            self.base_v3_url
+        );
+        let body = SendMessageRequest {
+            content,
+            attachments: &[],
         };
+        let response = self.execute(self.request_post(url, &body)?)?;
+        parse_message_response(response)
+    }
 
+    fn enrich_message_creators(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        messages: &mut [ChatMessage],
+    ) {
         let needs_creator_enrichment =
             messages
                 .iter()
@@ -316,36 +903,8 @@ impl ClickUpApi {
         if needs_creator_enrichment
             && let Ok(members) = self.get_channel_members(workspace_id, channel_id)
         {
-            let members_by_id: HashMap<&str, &ChannelMember> = members
-                .iter()
-                .map(|member| (member.id.as_str(), member))
-                .collect();
-
-            for message in &mut messages {
-                if matches!(
-                    message.creator.as_ref(),
-                    Some(creator) if creator.username.is_some() || creator.email.is_some()
-                ) {
-                    continue;
-                }
-
-                let creator_id = message.creator_id();
-                if creator_id == "0" {
-                    continue;
-                }
-
-                if let Some(member) = members_by_id.get(creator_id.as_str()) {
-                    message.creator = Some(MessageCreator {
-                        id: member.id.clone(),
-                        username: member.username.clone(),
-                        email: member.email.clone(),
-                        profile_picture: member.profile_picture.clone(),
-                    });
-                }
-            }
+            resolve_message_creators(messages, &members);
         }
-
-        Ok(messages)
     }
 
     pub fn send_message(
@@ -353,29 +912,170 @@ impl ClickUpApi {
         workspace_id: u64,
         channel_id: &str,
         content: &str,
+        attachments: &[MessageAttachment],
     ) -> Result<ChatMessage, AppError> {
         let url = format!(
             "{}/workspaces/{workspace_id}/chat/channels/{channel_id}/messages",
             self.base_v3_url
         );
         let body = SendMessageRequest {
+            content,
+            attachments,
+        };
+        let response = self.execute(self.request_post(url, &body)?)?;
+        let mut message = parse_message_response(response)?;
+        if message.attachments.is_empty() {
+            message.attachments = attachments.to_vec();
+        }
+        Ok(message)
+    }
+
+    pub fn edit_message(
+        &self,
+        workspace_id: u64,
+        message_id: &str,
+        content: &str,
+    ) -> Result<ChatMessage, AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/messages/{message_id}",
+            self.base_v3_url
+        );
+        let body = EditMessageRequest {
             content: content.to_string(),
         };
-        let response = self.request_post(url, &body)?.send()?;
+        let response = self.execute(self.request_patch(url, &body)?)?;
+        parse_message_response(response)
+    }
+
+    pub fn add_reaction(
+        &self,
+        workspace_id: u64,
+        message_id: &str,
+        reaction: &str,
+    ) -> Result<(), AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/messages/{message_id}/reactions",
+            self.base_v3_url
+        );
+        let body = ReactionRequest { reaction };
+        ensure_success(self.execute(self.request_post(url, &body)?)?)?;
+        Ok(())
+    }
 
-        let status = response.status();
-        let text = response
-            .text()
-            .map_err(|e| AppError::Parse(e.to_string()))?;
+    pub fn remove_reaction(
+        &self,
+        workspace_id: u64,
+        message_id: &str,
+        reaction: &str,
+    ) -> Result<(), AppError> {
+        let mut url = Url::parse(&format!(
+            "{}/workspaces/{workspace_id}/chat/messages/{message_id}/reactions",
+            self.base_v3_url
+        ))
+        .map_err(|err| AppError::Config(format!("Invalid reaction URL: {err}")))?;
+        url.path_segments_mut()
+            .map_err(|_| AppError::Config("Invalid reaction URL".to_string()))?
+            .push(reaction);
+        ensure_success(self.execute(self.request_delete(url.to_string())?)?)?;
+        Ok(())
+    }
+
+    pub fn delete_message(&self, workspace_id: u64, message_id: &str) -> Result<(), AppError> {
+        let url = format!(
+            "{}/workspaces/{workspace_id}/chat/messages/{message_id}",
+            self.base_v3_url
+        );
+        ensure_success(self.execute(self.request_delete(url)?)?)?;
+        Ok(())
+    }
+}
 
-        if !status.is_success() {
-            return Err(AppError::Api(format!("API error {}: {}", status, text)));
+pub fn canonical_reaction(reaction: &str) -> &str {
+    match reaction {
+        "+1" => "thumbsup",
+        "-1" => "thumbsdown",
+        _ => REACTION_EMOJIS
+            .iter()
+            .find(|(_, emoji)| *emoji == reaction)
+            .map_or(reaction, |(name, _)| name),
+    }
+}
+
+pub fn reaction_emoji(reaction: &str) -> &str {
+    let name = canonical_reaction(reaction);
+    REACTION_EMOJIS
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map_or(reaction, |(_, emoji)| emoji)
+}
+
+pub fn resolve_message_creators(messages: &mut [ChatMessage], members: &[ChannelMember]) {
+    let members_by_id: HashMap<&str, &ChannelMember> = members
+        .iter()
+        .map(|member| (member.id.as_str(), member))
+        .collect();
+
+    for message in messages.iter_mut() {
+        if matches!(
+            message.creator.as_ref(),
+            Some(creator) if creator.username.is_some() || creator.email.is_some()
+        ) {
+            continue;
         }
 
-        let parsed: ChatMessage = serde_json::from_str(&text).map_err(|e| {
-            AppError::Parse(format!("JSON parse error: {} - body was: {}", e, text))
-        })?;
+        let creator_id = message.creator_id();
+        if creator_id == "0" {
+            continue;
+        }
 
-        Ok(parsed)
+        if let Some(member) = members_by_id.get(creator_id.as_str()) {
+            message.creator = Some(MessageCreator {
+                id: member.id.clone(),
+                username: member.username.clone(),
+                email: member.email.clone(),
+                profile_picture: member.profile_picture.clone(),
+            });
+        }
     }
 }
+
+fn parse_messages_page(
+    response: reqwest::blocking::Response,
+) -> Result<ChatMessagesPage, AppError> {
+    let response = ensure_success(response)?;
+    let text = response
+        .text()
+        .map_err(|e| AppError::Parse(e.to_string()))?;
+
+    match serde_json::from_str::<GetMessagesResponse>(&text) {
+        Ok(body) => Ok(ChatMessagesPage {
+            messages: body.data,
+            next_cursor: body.next_cursor.filter(|c| !c.is_empty()),
+        }),
+        Err(_) => match serde_json::from_str::<Vec<ChatMessage>>(&text) {
+            Ok(messages) => Ok(ChatMessagesPage {
+                messages,
+                next_cursor: None,
+            }),
+            Err(_) => Err(AppError::Parse(
+                "Failed to parse messages response".to_string(),
+            )),
+        },
+    }
+}
+
+fn parse_message_response(response: reqwest::blocking::Response) -> Result<ChatMessage, AppError> {
+    let status = response.status();
+    let text = response
+        .text()
+        .map_err(|e| AppError::Parse(e.to_string()))?;
+
+    if !status.is_success() {
+        return Err(api_error(status, &text));
+    }
+
+    let parsed: ChatMessage = serde_json::from_str(&text)
+        .map_err(|e| AppError::Parse(format!("JSON parse error: {} - body was: {}", e, text)))?;
+
+    Ok(parsed)
+}
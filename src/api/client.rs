@@ -1,7 +1,18 @@
 use crate::error::AppError;
-use reqwest::blocking::{Client, RequestBuilder, Response};
-use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::blocking::multipart::Form;
+use reqwest::blocking::{Client, Request, RequestBuilder, Response};
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub(crate) const DEFAULT_API_BASE: &str = "https://api.clickup.com/api";
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(30);
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(5);
+const RETRY_BACKOFF_BASE: Duration = Duration::from_secs(1);
+const LOW_RATE_LIMIT_REMAINING: u64 = 10;
 
 #[derive(Clone, Debug)]
 pub struct ClickUpApi {
@@ -9,17 +20,166 @@ pub struct ClickUpApi {
     pub(crate) base_v3_url: String,
     pub(crate) token: String,
     pub(crate) client: Client,
+    pub(crate) rate_limit: Arc<Mutex<RateLimitState>>,
 }
 
-impl ClickUpApi {
-    pub fn new(token: impl Into<String>) -> Result<Self, AppError> {
-        Ok(Self {
-            base_v2_url: "https://api.clickup.com/api/v2".to_string(),
-            base_v3_url: "https://api.clickup.com/api/v3".to_string(),
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RateLimitState {
+    pub remaining: Option<u64>,
+    pub reset_at: Option<SystemTime>,
+}
+
+impl RateLimitState {
+    fn update_from_headers(&mut self, headers: &HeaderMap) {
+        if let Some(remaining) = header_u64(headers, "X-RateLimit-Remaining") {
+            self.remaining = Some(remaining);
+        }
+        if let Some(reset) = header_u64(headers, "X-RateLimit-Reset") {
+            self.reset_at = Some(UNIX_EPOCH + Duration::from_secs(reset));
+        }
+    }
+
+    pub fn is_low(&self) -> bool {
+        self.remaining
+            .is_some_and(|remaining| remaining <= LOW_RATE_LIMIT_REMAINING)
+            && self
+                .reset_at
+                .is_some_and(|reset_at| reset_at > SystemTime::now())
+    }
+
+    pub fn wait_before_next_request(&self) -> Option<Duration> {
+        if self.remaining != Some(0) {
+            return None;
+        }
+
+        self.reset_at
+            .and_then(|reset_at| reset_at.duration_since(SystemTime::now()).ok())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ClickUpApiBuilder {
+    token: String,
+    base_v2_url: String,
+    base_v3_url: String,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    client: Option<Client>,
+}
+
+impl ClickUpApiBuilder {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
             token: token.into(),
-            client: Client::new(),
+            base_v2_url: format!("{DEFAULT_API_BASE}/v2"),
+            base_v3_url: format!("{DEFAULT_API_BASE}/v3"),
+            timeout: None,
+            user_agent: None,
+            client: None,
+        }
+    }
+
+    pub fn api_base(mut self, base: impl AsRef<str>) -> Self {
+        let base = base.as_ref().trim_end_matches('/');
+        self.base_v2_url = format!("{base}/v2");
+        self.base_v3_url = format!("{base}/v3");
+        self
+    }
+
+    pub fn base_v2_url(mut self, url: impl Into<String>) -> Self {
+        self.base_v2_url = trim_base_url(url.into());
+        self
+    }
+
+    pub fn base_v3_url(mut self, url: impl Into<String>) -> Self {
+        self.base_v3_url = trim_base_url(url.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn env_overrides(mut self) -> Result<Self, AppError> {
+        if let Some(base) = env_value("CLICKUP_API_BASE") {
+            self = self.api_base(base);
+        }
+        if let Some(url) = env_value("CLICKUP_API_V2_URL") {
+            self = self.base_v2_url(url);
+        }
+        if let Some(url) = env_value("CLICKUP_API_V3_URL") {
+            self = self.base_v3_url(url);
+        }
+        if let Some(secs) = env_value("CLICKUP_API_TIMEOUT_SECS") {
+            let secs = secs.parse::<u64>().map_err(|_| {
+                AppError::Config(format!(
+                    "CLICKUP_API_TIMEOUT_SECS must be a whole number of seconds, got '{secs}'"
+                ))
+            })?;
+            self = self.timeout(Duration::from_secs(secs));
+        }
+        if let Some(user_agent) = env_value("CLICKUP_API_USER_AGENT") {
+            self = self.user_agent(user_agent);
+        }
+        Ok(self)
+    }
+
+    pub fn build(self) -> Result<ClickUpApi, AppError> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder =
+                    Client::builder()
+                        .user_agent(self.user_agent.unwrap_or_else(|| {
+                            format!("click-lite/{}", env!("CARGO_PKG_VERSION"))
+                        }));
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(ClickUpApi {
+            base_v2_url: self.base_v2_url,
+            base_v3_url: self.base_v3_url,
+            token: self.token,
+            client,
+            rate_limit: Arc::new(Mutex::new(RateLimitState::default())),
         })
     }
+}
+
+fn trim_base_url(url: String) -> String {
+    url.trim_end_matches('/').to_string()
+}
+
+pub(crate) fn env_value(key: &str) -> Option<String> {
+    std::env::var(key)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+impl ClickUpApi {
+    pub fn new(token: impl Into<String>) -> Result<Self, AppError> {
+        Self::builder(token).build()
+    }
+
+    pub fn builder(token: impl Into<String>) -> ClickUpApiBuilder {
+        ClickUpApiBuilder::new(token)
+    }
 
     pub fn from_env() -> Result<Self, AppError> {
         let token = std::env::var("CLICKUP_ACCESS_TOKEN")
@@ -29,7 +189,7 @@ impl ClickUpApi {
                     "missing CLICKUP_ACCESS_TOKEN (or CLICKUP_TOKEN) in environment".to_string(),
                 )
             })?;
-        Self::new(token)
+        Self::builder(token).env_overrides()?.build()
     }
 
     pub(crate) fn request_get(&self, url: String) -> Result<RequestBuilder, AppError> {
@@ -51,15 +211,157 @@ impl ClickUpApi {
             .header(CONTENT_TYPE, "application/json")
             .json(body))
     }
+
+    pub(crate) fn request_delete(&self, url: String) -> Result<RequestBuilder, AppError> {
+        Ok(self
+            .client
+            .delete(url)
+            .header(AUTHORIZATION, self.token.clone()))
+    }
+
+    pub(crate) fn request_multipart(
+        &self,
+        url: String,
+        form: Form,
+    ) -> Result<RequestBuilder, AppError> {
+        Ok(self
+            .client
+            .post(url)
+            .header(AUTHORIZATION, self.token.clone())
+            .multipart(form))
+    }
+
+    pub(crate) fn request_patch<T: Serialize>(
+        &self,
+        url: String,
+        body: &T,
+    ) -> Result<RequestBuilder, AppError> {
+        Ok(self
+            .client
+            .patch(url)
+            .header(AUTHORIZATION, self.token.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .json(body))
+    }
+}
+
+impl ClickUpApi {
+    pub fn rate_limit_state(&self) -> RateLimitState {
+        self.rate_limit
+            .lock()
+            .map(|state| *state)
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn execute(&self, request: RequestBuilder) -> Result<Response, AppError> {
+        let request = request.build()?;
+        let retryable = request.method() == Method::GET;
+        let mut attempt = 0;
+
+        loop {
+            if let Some(wait) = self.rate_limit_state().wait_before_next_request() {
+                if !retryable || wait > MAX_RATE_LIMIT_WAIT {
+                    return Err(AppError::RateLimited { retry_after: wait });
+                }
+                std::thread::sleep(wait);
+            }
+
+            let Some(next_request) = request.try_clone().filter(|_| retryable) else {
+                return self.execute_once(request);
+            };
+
+            match self.execute_once(next_request) {
+                Err(AppError::RateLimited { retry_after })
+                    if attempt < MAX_RATE_LIMIT_RETRIES && retry_after <= MAX_RATE_LIMIT_WAIT =>
+                {
+                    let backoff = RETRY_BACKOFF_BASE * 2u32.pow(attempt);
+                    attempt += 1;
+                    std::thread::sleep(retry_after.max(backoff));
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn execute_once(&self, request: Request) -> Result<Response, AppError> {
+        let response = self.client.execute(request)?;
+
+        if let Ok(mut state) = self.rate_limit.lock() {
+            state.update_from_headers(response.headers());
+        }
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = retry_after(response.headers()).unwrap_or(DEFAULT_RETRY_AFTER);
+            return Err(AppError::RateLimited { retry_after });
+        }
+
+        Ok(response)
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+}
+
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(secs) = header_u64(headers, RETRY_AFTER.as_str()) {
+        return Some(Duration::from_secs(secs));
+    }
+
+    header_u64(headers, "X-RateLimit-Reset").map(|reset| {
+        (UNIX_EPOCH + Duration::from_secs(reset))
+            .duration_since(SystemTime::now())
+            .unwrap_or_default()
+    })
 }
 
 pub fn ensure_success(response: Response) -> Result<Response, AppError> {
     let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after(response.headers()).unwrap_or(DEFAULT_RETRY_AFTER);
+        return Err(AppError::RateLimited { retry_after });
+    }
     if status.is_success() {
         Ok(response)
     } else {
         let body = response.text().unwrap_or_default();
-        Err(AppError::Api(format!("ClickUp returned {status}: {body}")))
+        Err(api_error(status, &body))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ClickUpErrorBody {
+    #[serde(default, alias = "error", alias = "message")]
+    err: Option<String>,
+    #[serde(rename = "ECODE", default)]
+    ecode: Option<String>,
+}
+
+pub fn api_error(status: StatusCode, body: &str) -> AppError {
+    let parsed = serde_json::from_str::<ClickUpErrorBody>(body).ok();
+    let code = parsed.as_ref().and_then(|body| body.ecode.clone());
+    let message = parsed
+        .and_then(|body| body.err)
+        .filter(|message| !message.is_empty())
+        .unwrap_or_else(|| {
+            let body = body.trim();
+            if body.is_empty() {
+                status
+                    .canonical_reason()
+                    .unwrap_or("Unknown error")
+                    .to_string()
+            } else {
+                body.to_string()
+            }
+        });
+
+    AppError::ClickUp {
+        status: status.as_u16(),
+        code,
+        message,
     }
 }
 
@@ -1,7 +1,21 @@
+mod attachments;
 mod chats;
 mod client;
+mod oauth;
+mod tasks;
 mod users;
+mod workspaces;
 
-pub use chats::{ChannelMember, ChatMessage, ClickUpChatChannel, MessageCreator};
-pub use client::ClickUpApi;
+pub use attachments::MessageAttachment;
+pub use chats::{
+    ChannelMember, ChatChannelsPage, ChatMessage, ChatMessagesPage, ClickUpChatChannel,
+    MENTION_REGEX, MESSAGES_PAGE_SIZE, MessageCreator, MessageReaction, PageCursors,
+    ReactionSummary, canonical_reaction, reaction_emoji, resolve_message_creators,
+};
+pub use client::{ClickUpApi, ClickUpApiBuilder, RateLimitState};
+pub use oauth::{OAuthConfig, OAuthSession};
+pub use tasks::{
+    ClickUpTask, NewTask, TaskAssignee, TaskList, TaskRef, TaskStatus, find_task_refs,
+};
 pub use users::ClickUpUser;
+pub use workspaces::ClickUpWorkspace;
@@ -0,0 +1,240 @@
+use crate::api::client::{ClickUpApi, DEFAULT_API_BASE, api_error, env_value};
+use crate::error::AppError;
+use reqwest::Url;
+use serde::Deserialize;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+const AUTHORIZE_URL: &str = "https://app.clickup.com/api";
+const REDIRECT_HOST: &str = "127.0.0.1";
+const DEFAULT_REDIRECT_PORT: u16 = 53682;
+const CALLBACK_PATH: &str = "/callback";
+const CALLBACK_TIMEOUT: Duration = Duration::from_secs(300);
+const CALLBACK_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+const CALLBACK_SUCCESS_PAGE: &str = "<!doctype html><html><body style=\"font-family: sans-serif\">\
+    <h2>Signed in to ClickLite</h2><p>You can close this window and return to the app.</p>\
+    </body></html>";
+const CALLBACK_FAILURE_PAGE: &str = "<!doctype html><html><body style=\"font-family: sans-serif\">\
+    <h2>Sign-in failed</h2><p>Return to ClickLite and try again.</p></body></html>";
+
+#[derive(Clone, Debug)]
+pub struct OAuthConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_port: u16,
+    pub api_base: String,
+}
+
+#[derive(Debug)]
+pub struct OAuthSession {
+    config: OAuthConfig,
+    listener: TcpListener,
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+impl OAuthConfig {
+    pub fn from_env() -> Result<Self, AppError> {
+        let client_id = env_value("CLICKUP_CLIENT_ID").ok_or_else(|| {
+            AppError::Config("missing CLICKUP_CLIENT_ID in environment".to_string())
+        })?;
+        let client_secret = env_value("CLICKUP_CLIENT_SECRET").ok_or_else(|| {
+            AppError::Config("missing CLICKUP_CLIENT_SECRET in environment".to_string())
+        })?;
+        let redirect_port = match env_value("CLICKUP_OAUTH_PORT") {
+            Some(port) => port.parse::<u16>().map_err(|_| {
+                AppError::Config(format!(
+                    "CLICKUP_OAUTH_PORT must be a port number, got '{port}'"
+                ))
+            })?,
+            None => DEFAULT_REDIRECT_PORT,
+        };
+        let api_base = env_value("CLICKUP_API_BASE")
+            .map(|base| base.trim_end_matches('/').to_string())
+            .unwrap_or_else(|| DEFAULT_API_BASE.to_string());
+
+        Ok(Self {
+            client_id,
+            client_secret,
+            redirect_port,
+            api_base,
+        })
+    }
+
+    pub fn is_configured() -> bool {
+        env_value("CLICKUP_CLIENT_ID").is_some() && env_value("CLICKUP_CLIENT_SECRET").is_some()
+    }
+
+    pub fn redirect_uri(&self) -> String {
+        format!(
+            "http://{REDIRECT_HOST}:{}{CALLBACK_PATH}",
+            self.redirect_port
+        )
+    }
+
+    pub fn authorize_url(&self, state: &str) -> Result<Url, AppError> {
+        Url::parse_with_params(
+            AUTHORIZE_URL,
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("redirect_uri", self.redirect_uri().as_str()),
+                ("state", state),
+            ],
+        )
+        .map_err(|err| AppError::Config(format!("Invalid OAuth authorize URL: {err}")))
+    }
+
+    pub fn start(&self) -> Result<(OAuthSession, Url), AppError> {
+        let listener = TcpListener::bind((REDIRECT_HOST, self.redirect_port))?;
+        listener.set_nonblocking(true)?;
+
+        let state = new_state()?;
+        let url = self.authorize_url(&state)?;
+        let session = OAuthSession {
+            config: self.clone(),
+            listener,
+            state,
+        };
+        Ok((session, url))
+    }
+
+    pub fn exchange_code(&self, code: &str) -> Result<String, AppError> {
+        let url = Url::parse_with_params(
+            &format!("{}/v2/oauth/token", self.api_base),
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("code", code),
+            ],
+        )
+        .map_err(|err| AppError::Config(format!("Invalid OAuth token URL: {err}")))?;
+
+        let api = ClickUpApi::builder(String::new())
+            .env_overrides()?
+            .api_base(&self.api_base)
+            .build()?;
+        let response = api.execute(api.client.post(url))?;
+        let status = response.status();
+        let text = response
+            .text()
+            .map_err(|e| AppError::Parse(e.to_string()))?;
+
+        if !status.is_success() {
+            return Err(api_error(status, &text));
+        }
+
+        let body: TokenResponse = serde_json::from_str(&text)
+            .map_err(|e| AppError::Parse(format!("Invalid OAuth token response: {e}")))?;
+        Ok(body.access_token)
+    }
+}
+
+impl OAuthSession {
+    pub fn wait_for_token(self) -> Result<String, AppError> {
+        let code = wait_for_callback(&self.listener, &self.state)?;
+        self.config.exchange_code(&code)
+    }
+}
+
+fn wait_for_callback(listener: &TcpListener, expected_state: &str) -> Result<String, AppError> {
+    let deadline = Instant::now() + CALLBACK_TIMEOUT;
+
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Some(result) = handle_callback(stream, expected_state) {
+                    return result;
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                if Instant::now() >= deadline {
+                    return Err(AppError::OAuth(
+                        "timed out waiting for the browser callback".to_string(),
+                    ));
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+fn handle_callback(
+    mut stream: TcpStream,
+    expected_state: &str,
+) -> Option<Result<String, AppError>> {
+    stream.set_nonblocking(false).ok()?;
+    stream.set_read_timeout(Some(CALLBACK_READ_TIMEOUT)).ok()?;
+
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line).ok()?;
+
+    let mut parts = request_line.split_whitespace();
+    let (Some("GET"), Some(target)) = (parts.next(), parts.next()) else {
+        let _ = write_response(&mut stream, "400 Bad Request", "");
+        return None;
+    };
+    let Ok(url) = Url::parse(&format!("http://{REDIRECT_HOST}{target}")) else {
+        let _ = write_response(&mut stream, "400 Bad Request", "");
+        return None;
+    };
+
+    if url.path() != CALLBACK_PATH {
+        let _ = write_response(&mut stream, "404 Not Found", "");
+        return None;
+    }
+
+    let query_value = |key: &str| {
+        url.query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    let result = match (
+        query_value("code"),
+        query_value("state"),
+        query_value("error"),
+    ) {
+        (Some(code), Some(state), _) if state == expected_state => Ok(code),
+        (Some(_), _, _) => Err(AppError::OAuth(
+            "callback state did not match this sign-in attempt".to_string(),
+        )),
+        (None, _, Some(error)) => Err(AppError::OAuth(format!("sign-in was cancelled ({error})"))),
+        (None, _, None) => {
+            let _ = write_response(&mut stream, "400 Bad Request", "");
+            return None;
+        }
+    };
+
+    let page = if result.is_ok() {
+        CALLBACK_SUCCESS_PAGE
+    } else {
+        CALLBACK_FAILURE_PAGE
+    };
+    let _ = write_response(&mut stream, "200 OK", page);
+
+    Some(result)
+}
+
+fn write_response(stream: &mut TcpStream, status: &str, body: &str) -> Result<(), AppError> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn new_state() -> Result<String, AppError> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes)
+        .map_err(|err| AppError::OAuth(format!("could not generate sign-in state: {err}")))?;
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
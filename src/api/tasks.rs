@@ -0,0 +1,243 @@
+use crate::api::client::{ClickUpApi, parse_json_ok};
+use crate::error::AppError;
+use chrono::{DateTime, Local};
+use regex::Regex;
+use serde::de::Deserializer;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+const MAX_TASK_REFS_PER_MESSAGE: usize = 3;
+
+static TASK_URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"https?://app\.clickup\.com/t/(?:(\d+)/)?([A-Za-z0-9_-]+)").expect("Invalid regex")
+});
+static CUSTOM_TASK_ID_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b([A-Z][A-Z0-9]{1,9})-\d+\b").expect("Invalid regex"));
+static CODE_SPAN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)```.*?(?:```|$)|`[^`\n]*`").expect("Invalid regex"));
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskRef {
+    Id(String),
+    Custom(String),
+}
+
+impl TaskRef {
+    pub fn id(&self) -> &str {
+        match self {
+            TaskRef::Id(id) | TaskRef::Custom(id) => id,
+        }
+    }
+}
+
+pub fn find_task_refs(content: &str, known_prefixes: &HashSet<String>) -> Vec<TaskRef> {
+    let mut refs: Vec<TaskRef> = Vec::new();
+    let mut push = |task_ref: TaskRef| {
+        if !refs.contains(&task_ref) {
+            refs.push(task_ref);
+        }
+    };
+
+    for caps in TASK_URL_REGEX.captures_iter(content) {
+        let id = caps[2].to_string();
+        push(if caps.get(1).is_some() {
+            TaskRef::Custom(id)
+        } else {
+            TaskRef::Id(id)
+        });
+    }
+
+    if !known_prefixes.is_empty() {
+        let without_urls = TASK_URL_REGEX.replace_all(content, " ");
+        let without_code = CODE_SPAN_REGEX.replace_all(&without_urls, " ");
+        for caps in CUSTOM_TASK_ID_REGEX.captures_iter(&without_code) {
+            if known_prefixes.contains(&caps[1]) {
+                push(TaskRef::Custom(caps[0].to_string()));
+            }
+        }
+    }
+
+    refs.truncate(MAX_TASK_REFS_PER_MESSAGE);
+    refs
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ClickUpTask {
+    pub id: String,
+    #[serde(default)]
+    pub custom_id: Option<String>,
+    pub name: String,
+    pub status: TaskStatus,
+    #[serde(default)]
+    pub assignees: Vec<TaskAssignee>,
+    #[serde(default, deserialize_with = "deserialize_optional_millis")]
+    pub due_date: Option<u64>,
+    pub url: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct TaskList {
+    pub id: String,
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct NewTask {
+    pub name: String,
+    pub description: String,
+    pub assignees: Vec<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedItem {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetSpacesResponse {
+    #[serde(default)]
+    spaces: Vec<NamedItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetFoldersResponse {
+    #[serde(default)]
+    folders: Vec<FolderWire>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FolderWire {
+    name: String,
+    #[serde(default)]
+    lists: Vec<NamedItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetListsResponse {
+    #[serde(default)]
+    lists: Vec<NamedItem>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TaskStatus {
+    pub status: String,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TaskAssignee {
+    pub id: u64,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+impl TaskAssignee {
+    pub fn display_name(&self) -> String {
+        self.username
+            .clone()
+            .filter(|name| !name.is_empty())
+            .or_else(|| self.email.clone())
+            .unwrap_or_else(|| self.id.to_string())
+    }
+}
+
+impl ClickUpTask {
+    pub fn display_id(&self) -> &str {
+        self.custom_id.as_deref().unwrap_or(&self.id)
+    }
+
+    pub fn custom_id_prefix(&self) -> Option<&str> {
+        let (prefix, number) = self.custom_id.as_deref()?.rsplit_once('-')?;
+        (!prefix.is_empty() && !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+            .then_some(prefix)
+    }
+
+    pub fn due_date_label(&self) -> Option<String> {
+        let due = DateTime::from_timestamp_millis(i64::try_from(self.due_date?).ok()?)?;
+        Some(format!(
+            "Due {}",
+            due.with_timezone(&Local).format("%b %-d, %Y")
+        ))
+    }
+
+    pub fn is_overdue(&self, now_millis: u64) -> bool {
+        self.due_date.is_some_and(|due| due < now_millis)
+    }
+}
+
+fn deserialize_optional_millis<'de, Des>(deserializer: Des) -> Result<Option<u64>, Des::Error>
+where
+    Des: Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+
+    match value {
+        serde_json::Value::Null => Ok(None),
+        serde_json::Value::String(text) => Ok(text.parse::<u64>().ok()),
+        serde_json::Value::Number(number) => Ok(number.as_u64()),
+        _ => Err(serde::de::Error::custom("expected string or number")),
+    }
+}
+
+impl ClickUpApi {
+    pub fn get_task(&self, workspace_id: u64, task_ref: &TaskRef) -> Result<ClickUpTask, AppError> {
+        let url = format!("{}/task/{}", self.base_v2_url, task_ref.id());
+        let mut request = self.request_get(url)?;
+        if let TaskRef::Custom(_) = task_ref {
+            request = request.query(&[
+                ("custom_task_ids", "true".to_string()),
+                ("team_id", workspace_id.to_string()),
+            ]);
+        }
+
+        let response = self.execute(request)?;
+        parse_json_ok(response)
+    }
+
+    pub fn get_task_lists(&self, workspace_id: u64) -> Result<Vec<TaskList>, AppError> {
+        let url = format!(
+            "{}/team/{workspace_id}/space?archived=false",
+            self.base_v2_url
+        );
+        let spaces: GetSpacesResponse = parse_json_ok(self.execute(self.request_get(url)?)?)?;
+
+        let mut lists = Vec::new();
+        for space in spaces.spaces {
+            let url = format!(
+                "{}/space/{}/folder?archived=false",
+                self.base_v2_url, space.id
+            );
+            let folders: GetFoldersResponse = parse_json_ok(self.execute(self.request_get(url)?)?)?;
+            for folder in folders.folders {
+                lists.extend(folder.lists.into_iter().map(|list| TaskList {
+                    id: list.id,
+                    path: format!("{} / {} / {}", space.name, folder.name, list.name),
+                }));
+            }
+
+            let url = format!(
+                "{}/space/{}/list?archived=false",
+                self.base_v2_url, space.id
+            );
+            let folderless: GetListsResponse =
+                parse_json_ok(self.execute(self.request_get(url)?)?)?;
+            lists.extend(folderless.lists.into_iter().map(|list| TaskList {
+                id: list.id,
+                path: format!("{} / {}", space.name, list.name),
+            }));
+        }
+
+        Ok(lists)
+    }
+
+    pub fn create_task(&self, list_id: &str, task: &NewTask) -> Result<ClickUpTask, AppError> {
+        let url = format!("{}/list/{list_id}/task", self.base_v2_url);
+        let response = self.execute(self.request_post(url, task)?)?;
+        parse_json_ok(response)
+    }
+}
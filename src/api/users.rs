@@ -1,10 +1,10 @@
 use crate::api::client::{ClickUpApi, parse_json_ok};
 use crate::error::AppError;
 use gpui::{Image, ImageFormat};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ClickUpUser {
     pub id: u64,
     pub username: String,
@@ -39,7 +39,7 @@ struct GetTeamMember {
 impl ClickUpApi {
     pub fn get_current_user(&self) -> Result<ClickUpUser, AppError> {
         let url = format!("{}/user", self.base_v2_url);
-        let response = self.request_get(url)?.send()?;
+        let response = self.execute(self.request_get(url)?)?;
         let body: GetUserResponse = parse_json_ok(response)?;
 
         let mut user = body.user;
@@ -57,7 +57,7 @@ impl ClickUpApi {
 
     pub fn get_team_members(&self, workspace_id: u64) -> Result<Vec<ClickUpUser>, AppError> {
         let url = format!("{}/team/{workspace_id}", self.base_v2_url);
-        let response = self.request_get(url)?.send()?;
+        let response = self.execute(self.request_get(url)?)?;
         let body: GetTeamResponse = parse_json_ok(response)?;
 
         Ok(body
@@ -0,0 +1,30 @@
+use crate::api::chats::deserialize_u64_from_string_or_number;
+use crate::api::client::{ClickUpApi, parse_json_ok};
+use crate::error::AppError;
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ClickUpWorkspace {
+    #[serde(deserialize_with = "deserialize_u64_from_string_or_number")]
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub avatar: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetWorkspacesResponse {
+    #[serde(default)]
+    teams: Vec<ClickUpWorkspace>,
+}
+
+impl ClickUpApi {
+    pub fn get_workspaces(&self) -> Result<Vec<ClickUpWorkspace>, AppError> {
+        let url = format!("{}/team", self.base_v2_url);
+        let response = self.execute(self.request_get(url)?)?;
+        let body: GetWorkspacesResponse = parse_json_ok(response)?;
+        Ok(body.teams)
+    }
+}
@@ -1,30 +1,144 @@
-use crate::api::{ChatMessage, ClickUpApi, ClickUpChatChannel, ClickUpUser};
-use crate::ui::{render_chat_area, render_header, render_sidebar};
+use crate::api::{
+    ChannelMember, ChatChannelsPage, ChatMessage, ChatMessagesPage, ClickUpApi, ClickUpChatChannel,
+    ClickUpTask, ClickUpUser, ClickUpWorkspace, MessageAttachment, OAuthConfig, PageCursors,
+    TaskList, TaskRef, canonical_reaction, find_task_refs, resolve_message_creators,
+};
+use crate::cache::LocalCache;
+use crate::commands::{CommandOutcome, CommandRegistry, SlashCommand, parse_command};
+use crate::credentials::{CredentialStore, api_for_token};
+use crate::error::AppError;
+use crate::export::{ChannelExport, ExportFormat, export_file_name};
+use crate::notifications::DesktopNotifier;
+use crate::outbox::{OutboxEntry, nonce_from_message_id};
+use crate::search::{SearchHit, SearchIndex, SearchScope};
+use crate::settings::Settings;
+use crate::ui::{
+    CreateTaskForm, CreateTaskRequest, NewChatForm, NewChatRequest, PeoplePicker,
+    render_channel_details, render_chat_area, render_header, render_search_panel, render_sidebar,
+    render_thread_panel, task_name_from_content,
+};
+use futures::StreamExt as _;
 use gpui::{
-    AnyWindowHandle, Context, Entity, FocusHandle, Image, ScrollHandle, SharedString, Subscription,
-    Window, div, prelude::*,
+    AnyWindowHandle, Context, Entity, FocusHandle, Image, PathPromptOptions, Pixels, ScrollHandle,
+    SharedString, Subscription, Window, actions, div, point, prelude::*, px,
 };
 use gpui_component::ActiveTheme as _;
-use gpui_component::input::{InputEvent, InputState};
-use std::collections::HashSet;
+use gpui_component::input::{InputEvent, InputState, RopeExt as _};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Range;
+use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Instant;
+
+const MENTION_SUGGESTION_LIMIT: usize = 6;
+const CHANNEL_ACTIVITY_REFRESH_TICKS: u32 = 6;
+const SEARCH_RESULT_LIMIT: usize = 50;
+const TASK_PREVIEW_CONCURRENCY: usize = 2;
+const UNREAD_COUNT_BATCH: usize = 5;
+const RETRY_BASE_DELAY_SECS: u64 = 5;
+const RETRY_MAX_DELAY_SECS: u64 = 5 * 60;
+const SETTINGS_SAVE_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+
+actions!(click_lite, [SearchInChannel, SearchWorkspace]);
+
+type AppCallback = Box<dyn FnOnce(&mut ClickLiteApp, &mut Context<ClickLiteApp>)>;
+
+struct DraftMention {
+    member: ChannelMember,
+    range: Range<usize>,
+}
+
+struct UnreadSummary {
+    channel_id: String,
+    latest: u64,
+    count: usize,
+    newest: Option<ChatMessage>,
+    mention: Option<ChatMessage>,
+}
 
 pub struct ClickLiteApp {
     pub clickup_status: SharedString,
     pub clickup_loading: bool,
+    pub signing_in: bool,
+    credentials_loading: bool,
+    token_rejected: bool,
+    session_expired: bool,
     pub user: Option<ClickUpUser>,
     pub team_id: Option<u64>,
+    pub workspaces: Vec<ClickUpWorkspace>,
+    pub workspaces_loading: bool,
+    pub team_members: Vec<ClickUpUser>,
+    pub team_members_loading: bool,
+    team_member_callbacks: Vec<AppCallback>,
+    task_lists: Vec<TaskList>,
+    pub task_lists_loading: bool,
+    task_list_callbacks: Vec<AppCallback>,
     pub channels: Vec<ClickUpChatChannel>,
     pub channels_loading: bool,
+    pub channels_loading_more: bool,
+    channels_generation: u64,
+    channel_activity_refreshing: bool,
+    workspace_generation: u64,
+    unread_counts: HashMap<String, usize>,
     pub selected_channel: Option<ClickUpChatChannel>,
     pub messages_loading: bool,
+    pub history_loading: bool,
     pub focus_handle: FocusHandle,
     pub scroll_handle: ScrollHandle,
     pub window_handle: AnyWindowHandle,
     pub message_input: Entity<InputState>,
+    pub editing_message_id: Option<String>,
+    pub thread_parent: Option<ChatMessage>,
+    pub thread_replies: Vec<ChatMessage>,
+    pub thread_loading: bool,
+    pub thread_sending: bool,
+    pub thread_scroll_handle: ScrollHandle,
+    pub thread_input: Entity<InputState>,
+    pub channel_details_open: bool,
+    pub channel_members: Vec<ChannelMember>,
+    pub channel_members_loading: bool,
+    pub exporting: bool,
+    pub search_input: Entity<InputState>,
+    pub search_open: bool,
+    pub search_scope: SearchScope,
+    pub search_query: String,
+    pub search_results: Vec<SearchHit>,
+    pub highlighted_message_id: Option<String>,
+    search_index: SearchIndex,
+    search_cache_loaded: bool,
+    scroll_target_id: Option<String>,
+    thread_generation: u64,
+    pub mention_query: Option<String>,
+    pub mention_selected: usize,
+    mention_members: Vec<ChannelMember>,
+    mention_start: Option<usize>,
+    draft_mentions: Vec<DraftMention>,
+    draft_value: String,
+    commands: Rc<CommandRegistry>,
+    command_query: Option<String>,
+    pub command_selected: usize,
+    pub uploading_attachments: usize,
+    attachment_images: HashMap<String, Arc<Image>>,
+    attachment_images_requested: HashSet<String>,
+    task_previews: HashMap<TaskRef, ClickUpTask>,
+    task_previews_requested: HashSet<TaskRef>,
+    task_preview_queue: VecDeque<TaskRef>,
+    task_previews_in_flight: usize,
+    api: Option<ClickUpApi>,
+    notifier: Option<DesktopNotifier>,
+    notification_targets: HashMap<u32, String>,
+    credentials: CredentialStore,
+    cache: LocalCache,
+    settings: Settings,
+    settings_save_scheduled: bool,
     server_messages: Vec<ChatMessage>,
     pending_messages: Vec<ChatMessage>,
-    pending_ids: HashSet<String>,
+    outbox: Vec<OutboxEntry>,
+    deleting_ids: HashSet<String>,
+    history_cursor: Option<String>,
+    history_scroll_anchor: Option<Pixels>,
+    refresh_paused_until: Option<Instant>,
+    retry_attempt: u32,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -34,39 +148,245 @@ impl ClickLiteApp {
         focus_handle: FocusHandle,
         window_handle: AnyWindowHandle,
         message_input: Entity<InputState>,
+        thread_input: Entity<InputState>,
+        search_input: Entity<InputState>,
         cx: &mut Context<Self>,
     ) -> Self {
+        let mut settings = Settings::load();
+        let team_id = settings.last_workspace_id.or(team_id);
+        if settings.unread_since.is_none() {
+            settings.unread_since = Some(now_millis());
+            let _ = settings.save();
+        }
+
         let mut app = Self {
             clickup_status: "Connecting...".into(),
             clickup_loading: false,
+            signing_in: false,
+            credentials_loading: false,
+            token_rejected: false,
+            session_expired: false,
+            api: None,
+            notifier: None,
+            notification_targets: HashMap::new(),
+            credentials: CredentialStore::new(),
+            cache: LocalCache::new(),
+            settings_save_scheduled: false,
+            settings,
             user: None,
             team_id,
+            workspaces: Vec::new(),
+            workspaces_loading: false,
+            team_members: Vec::new(),
+            team_members_loading: false,
+            team_member_callbacks: Vec::new(),
+            task_lists: Vec::new(),
+            task_lists_loading: false,
+            task_list_callbacks: Vec::new(),
             channels: Vec::new(),
             channels_loading: false,
+            channels_loading_more: false,
+            channels_generation: 0,
+            channel_activity_refreshing: false,
+            workspace_generation: 0,
+            unread_counts: HashMap::new(),
             selected_channel: None,
             server_messages: Vec::new(),
             pending_messages: Vec::new(),
-            pending_ids: HashSet::new(),
+            outbox: Vec::new(),
+            deleting_ids: HashSet::new(),
             messages_loading: false,
+            history_loading: false,
+            history_cursor: None,
+            history_scroll_anchor: None,
+            refresh_paused_until: None,
+            retry_attempt: 0,
             focus_handle,
             scroll_handle: ScrollHandle::new(),
             window_handle,
             message_input: message_input.clone(),
+            editing_message_id: None,
+            thread_parent: None,
+            thread_replies: Vec::new(),
+            thread_loading: false,
+            thread_sending: false,
+            thread_scroll_handle: ScrollHandle::new(),
+            thread_input: thread_input.clone(),
+            channel_details_open: false,
+            channel_members: Vec::new(),
+            channel_members_loading: false,
+            exporting: false,
+            search_input: search_input.clone(),
+            search_open: false,
+            search_scope: SearchScope::Channel,
+            search_query: String::new(),
+            search_results: Vec::new(),
+            highlighted_message_id: None,
+            search_index: SearchIndex::default(),
+            search_cache_loaded: false,
+            scroll_target_id: None,
+            thread_generation: 0,
+            mention_query: None,
+            mention_selected: 0,
+            mention_members: Vec::new(),
+            mention_start: None,
+            draft_mentions: Vec::new(),
+            draft_value: String::new(),
+            commands: Rc::new(CommandRegistry::with_builtins()),
+            command_query: None,
+            command_selected: 0,
+            uploading_attachments: 0,
+            attachment_images: HashMap::new(),
+            attachment_images_requested: HashSet::new(),
+            task_previews: HashMap::new(),
+            task_previews_requested: HashSet::new(),
+            task_preview_queue: VecDeque::new(),
+            task_previews_in_flight: 0,
             _subscriptions: Vec::new(),
         };
 
+        app.user = app.cache.load_user();
+        app.outbox = app.cache.load_outbox();
+        for entry in app.outbox.iter_mut().filter(|entry| entry.is_sending()) {
+            entry.fail("Interrupted before it was sent".to_string(), true);
+        }
+        if let Some(workspace_id) = team_id {
+            app.channels = app.cache.load_channels(workspace_id).unwrap_or_default();
+        }
+
         app._subscriptions.push(cx.subscribe(
             &message_input,
+            |this, _input, event: &InputEvent, cx| match event {
+                InputEvent::Change => {
+                    this.track_draft_edit(cx);
+                    this.update_mention_query(cx);
+                    this.update_command_query(cx);
+                }
+                InputEvent::PressEnter { secondary: false } => {
+                    if this.editing_message_id.is_some() {
+                        this.save_edit(cx);
+                    } else {
+                        this.send_message(cx);
+                    }
+                }
+                _ => {}
+            },
+        ));
+
+        app._subscriptions.push(cx.subscribe(
+            &thread_input,
             |this, _input, event: &InputEvent, cx| {
                 if let InputEvent::PressEnter { secondary: false } = event {
-                    this.send_message(cx);
+                    this.send_reply(cx);
+                }
+            },
+        ));
+
+        app._subscriptions.push(cx.subscribe(
+            &search_input,
+            |this, input, event: &InputEvent, cx| {
+                if let InputEvent::Change = event {
+                    this.search_query = input.read(cx).value().to_string();
+                    this.refresh_search_results();
+                    cx.notify();
                 }
             },
         ));
 
+        app.start_notifications(cx);
         app
     }
 
+    fn api(&self) -> Result<ClickUpApi, AppError> {
+        if self.settings.signed_out {
+            return Err(AppError::Credentials(
+                "Signed out, sign in to continue".to_string(),
+            ));
+        }
+        if self.session_expired {
+            return Err(AppError::Credentials(
+                "Session expired, sign in again to continue".to_string(),
+            ));
+        }
+        self.api.clone().ok_or_else(|| {
+            AppError::Credentials("Not connected to ClickUp yet, try again shortly".to_string())
+        })
+    }
+
+    fn load_credentials(&mut self, cx: &mut Context<Self>) {
+        if self.credentials_loading {
+            return;
+        }
+
+        self.credentials_loading = true;
+        self.clickup_status = "Connecting to ClickUp…".into();
+        cx.notify();
+
+        let credentials = self.credentials.clone();
+        cx.spawn(|this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+            let mut cx = cx.clone();
+            async move {
+                let result = cx
+                    .background_spawn(async move { credentials.load_api() })
+                    .await;
+
+                let _ = this.update(&mut cx, |view, cx| {
+                    view.credentials_loading = false;
+                    match result {
+                        Ok(api) => {
+                            view.api = Some(api);
+                            view.fetch_clickup_user(cx);
+                        }
+                        Err(err) => view.report_missing_api(&err, cx),
+                    }
+                });
+            }
+        })
+        .detach();
+    }
+
+    fn report_missing_api(&mut self, err: &AppError, cx: &mut Context<Self>) {
+        if OAuthConfig::is_configured() || self.settings.signed_out {
+            self.clickup_status = "Sign in to ClickUp to continue".into();
+        } else {
+            self.clickup_status = format!("{err}").into();
+            self.show_error_dialog("Connection failed", format!("{err}"), cx);
+        }
+        cx.notify();
+    }
+
+    pub fn sign_out(&mut self, cx: &mut Context<Self>) {
+        if let Err(err) = self.credentials.clear() {
+            self.show_error_dialog("Sign-out failed", format!("{err}"), cx);
+        }
+        self.settings.signed_out = true;
+        self.session_expired = false;
+        self.save_settings(cx);
+        if let Err(err) = self.cache.clear() {
+            self.show_error_dialog("Could not clear local cache", format!("{err}"), cx);
+        }
+
+        self.api = None;
+        self.user = None;
+        self.channels.clear();
+        self.selected_channel = None;
+        self.server_messages.clear();
+        self.outbox.clear();
+        self.sync_outbox_messages();
+        self.history_cursor = None;
+        self.editing_message_id = None;
+        self.reset_search_index();
+        self.reset_workspace_loads();
+        self.task_previews.clear();
+        self.task_previews_requested.clear();
+        self.task_preview_queue.clear();
+        self.close_search(cx);
+        self.close_thread(cx);
+        self.clickup_status = "Signed out".into();
+        self.set_message_input_placeholder("Select a chat to start messaging...", cx);
+        cx.notify();
+    }
+
     pub fn messages(&self) -> impl Iterator<Item = &ChatMessage> {
         self.server_messages
             .iter()
@@ -74,7 +394,19 @@ impl ClickLiteApp {
     }
 
     pub fn sending_message(&self) -> bool {
-        !self.pending_ids.is_empty()
+        self.outbox.iter().any(OutboxEntry::is_sending)
+    }
+
+    pub fn outbox_failure(&self, message_id: &str) -> Option<&str> {
+        let nonce = nonce_from_message_id(message_id)?;
+        self.outbox
+            .iter()
+            .find(|entry| entry.nonce == nonce)
+            .and_then(OutboxEntry::failure)
+    }
+
+    pub fn has_older_messages(&self) -> bool {
+        self.history_cursor.is_some()
     }
 
     fn set_message_input_placeholder(
@@ -92,6 +424,20 @@ impl ClickLiteApp {
         });
     }
 
+    fn restore_message_input_placeholder(&self, cx: &mut Context<Self>) {
+        match self.selected_channel.as_ref() {
+            Some(channel) => self.set_message_input_placeholder(
+                format!(
+                    "Message {}{}",
+                    channel.icon_prefix(),
+                    channel.display_name()
+                ),
+                cx,
+            ),
+            None => self.set_message_input_placeholder("Select a chat to start messaging...", cx),
+        }
+    }
+
     fn clear_message_input(&self, cx: &mut Context<Self>) {
         let input = self.message_input.clone();
         let window_handle = self.window_handle;
@@ -100,6 +446,29 @@ impl ClickLiteApp {
         });
     }
 
+    fn fill_message_input_at(&self, value: String, cursor: usize, cx: &mut Context<Self>) {
+        let input = self.message_input.clone();
+        let window_handle = self.window_handle;
+        let _ = cx.update_window(window_handle, move |_, window, cx| {
+            input.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+                let position = state.text().offset_to_position(cursor);
+                state.set_cursor_position(position, window, cx);
+            });
+        });
+    }
+
+    fn fill_message_input(&self, value: String, cx: &mut Context<Self>) {
+        let input = self.message_input.clone();
+        let window_handle = self.window_handle;
+        let _ = cx.update_window(window_handle, move |_, window, cx| {
+            input.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+                state.focus(window, cx);
+            });
+        });
+    }
+
     fn show_error_dialog(
         &self,
         title: impl Into<SharedString>,
@@ -125,18 +494,199 @@ impl ClickLiteApp {
         });
     }
 
+    fn mark_signed_in(&mut self, cx: &mut Context<Self>) {
+        self.session_expired = false;
+        if self.settings.signed_out {
+            self.settings.signed_out = false;
+            self.save_settings(cx);
+        }
+    }
+
+    fn save_settings(&mut self, cx: &mut Context<Self>) {
+        if self.settings_save_scheduled {
+            return;
+        }
+        self.settings_save_scheduled = true;
+
+        cx.spawn(|this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+            let mut cx = cx.clone();
+            async move {
+                cx.background_executor().timer(SETTINGS_SAVE_DELAY).await;
+                let Ok(settings) = this.update(&mut cx, |view, _cx| {
+                    view.settings_save_scheduled = false;
+                    view.settings.clone()
+                }) else {
+                    return;
+                };
+
+                let result = cx.background_spawn(async move { settings.save() }).await;
+                if let Err(err) = result {
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.clickup_status = format!("Could not save settings: {err}").into();
+                        cx.notify();
+                    });
+                }
+            }
+        })
+        .detach();
+    }
+
+    fn prompt_reauth(&mut self, cx: &mut Context<Self>) {
+        if self.session_expired {
+            return;
+        }
+
+        self.session_expired = true;
+        self.token_rejected = true;
+        self.api = None;
+        let credentials = self.credentials.clone();
+        cx.background_spawn(async move {
+            let _ = credentials.clear();
+        })
+        .detach();
+        self.user = None;
+        self.channels.clear();
+        self.selected_channel = None;
+        self.server_messages.clear();
+        self.clickup_status = "Session expired".into();
+        cx.notify();
+
+        let app_entity = cx.entity();
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            use gpui_component::WindowExt as _;
+            use gpui_component::dialog::DialogButtonProps;
+
+            window.open_dialog(cx, {
+                let app_entity = app_entity.clone();
+                move |dialog, _window, _cx| {
+                    let app_entity = app_entity.clone();
+                    dialog
+                        .title("Session expired")
+                        .child(div().text_sm().child(
+                            "ClickUp rejected your access token. Sign in again to continue.",
+                        ))
+                        .confirm()
+                        .button_props(DialogButtonProps::default().ok_text("Sign in"))
+                        .on_ok(move |_ev, _window, cx| {
+                            app_entity.update(cx, |this, cx| this.sign_in(cx));
+                            true
+                        })
+                }
+            });
+        });
+    }
+
+    fn remove_channel(&mut self, channel_id: &str, cx: &mut Context<Self>) {
+        self.channels.retain(|channel| channel.id != channel_id);
+        self.cache_channels(cx);
+        if self
+            .selected_channel
+            .as_ref()
+            .is_some_and(|channel| channel.id == channel_id)
+        {
+            self.selected_channel = None;
+            self.server_messages.clear();
+            self.sync_outbox_messages();
+            self.history_cursor = None;
+            self.close_thread(cx);
+            self.close_channel_details(cx);
+            self.set_message_input_placeholder("Select a chat to start messaging...", cx);
+        }
+        cx.notify();
+    }
+
+    fn channel_not_found(&mut self, channel_id: &str, cx: &mut Context<Self>) {
+        self.remove_channel(channel_id, cx);
+        self.show_error_dialog(
+            "Channel not found",
+            "This chat no longer exists or you no longer have access to it.",
+            cx,
+        );
+        cx.notify();
+    }
+
+    fn retry_later(
+        &mut self,
+        retry: impl FnOnce(&mut Self, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) {
+        let delay_secs = RETRY_BASE_DELAY_SECS
+            .saturating_mul(1 << self.retry_attempt.min(16))
+            .min(RETRY_MAX_DELAY_SECS);
+        self.retry_attempt = self.retry_attempt.saturating_add(1);
+        self.clickup_status = format!("Can't reach ClickUp, retrying in {delay_secs}s…").into();
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    cx.background_executor()
+                        .timer(std::time::Duration::from_secs(delay_secs))
+                        .await;
+                    let _ = this.update(&mut cx, retry);
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn handle_api_error(&mut self, err: &AppError, cx: &mut Context<Self>) -> bool {
+        if self.handle_rate_limit(err) {
+            return true;
+        }
+
+        if err.is_unauthorized() {
+            self.prompt_reauth(cx);
+            return true;
+        }
+
+        if err.is_offline() {
+            self.clickup_status = "Offline, showing cached data".into();
+            return true;
+        }
+
+        if err.is_server_error() {
+            self.clickup_status = format!("ClickUp is having trouble: {err}").into();
+            return true;
+        }
+
+        false
+    }
+
+    fn rate_limit_low(&self) -> bool {
+        self.api
+            .as_ref()
+            .is_some_and(|api| api.rate_limit_state().is_low())
+    }
+
+    fn handle_rate_limit(&mut self, err: &AppError) -> bool {
+        let AppError::RateLimited { retry_after } = err else {
+            return false;
+        };
+
+        self.refresh_paused_until = Some(Instant::now() + *retry_after);
+        self.clickup_status = format!("{err}").into();
+        true
+    }
+
     pub fn start_message_refresh(&mut self, cx: &mut Context<Self>) {
         cx.spawn(|this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
             let mut cx = cx.clone();
             async move {
+                let mut tick: u32 = 0;
                 loop {
                     cx.background_executor()
                         .timer(std::time::Duration::from_secs(5))
                         .await;
+                    tick = tick.wrapping_add(1);
 
                     let should_continue = this
                         .update(&mut cx, |view, cx| {
                             view.refresh_messages(cx);
+                            if tick.is_multiple_of(CHANNEL_ACTIVITY_REFRESH_TICKS) {
+                                view.refresh_channel_activity(cx);
+                            }
                             true
                         })
                         .unwrap_or(false);
@@ -161,47 +711,52 @@ impl ClickLiteApp {
         self.user.as_ref().and_then(|u| u.avatar_image.clone())
     }
 
-    pub fn fetch_clickup_user(&mut self, cx: &mut Context<Self>) {
-        if self.clickup_loading {
+    pub fn sign_in(&mut self, cx: &mut Context<Self>) {
+        if self.signing_in || self.clickup_loading || self.credentials_loading {
             return;
         }
 
-        self.clickup_loading = true;
-        self.clickup_status = "Connecting to ClickUp…".into();
-        cx.notify();
+        if !OAuthConfig::is_configured() {
+            if self.token_rejected {
+                self.show_token_dialog(cx);
+                return;
+            }
+            self.mark_signed_in(cx);
+            self.fetch_clickup_user(cx);
+            return;
+        }
 
-        let api = match ClickUpApi::from_env() {
-            Ok(api) => api,
+        let session = OAuthConfig::from_env().and_then(|config| config.start());
+        let (session, authorize_url) = match session {
+            Ok(session) => session,
             Err(err) => {
-                self.clickup_loading = false;
-                self.clickup_status = format!("{err}").into();
-                self.show_error_dialog("Connection failed", format!("{err}"), cx);
+                self.clickup_status = format!("Sign-in failed: {err}").into();
+                self.show_error_dialog("Sign-in failed", format!("{err}"), cx);
                 cx.notify();
                 return;
             }
         };
 
+        self.signing_in = true;
+        self.clickup_status = "Waiting for ClickUp sign-in…".into();
+        cx.open_url(authorize_url.as_str());
+        cx.notify();
+
         cx.spawn(|this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
             let mut cx = cx.clone();
             async move {
                 let result = cx
-                    .background_spawn(async move { api.get_current_user() })
+                    .background_spawn(async move { session.wait_for_token() })
                     .await;
 
-                let (status, user): (String, Option<ClickUpUser>) = match result {
-                    Ok(user) => (format!("Connected as {}", user.username), Some(user)),
-                    Err(err) => (format!("Connection failed: {err}"), None),
-                };
-                let status_for_dialog = status.clone();
-
                 let _ = this.update(&mut cx, |view, cx| {
-                    view.clickup_loading = false;
-                    view.clickup_status = status.into();
-                    view.user = user.clone();
-                    if user.is_some() {
-                        view.fetch_channels(cx);
-                    } else {
-                        view.show_error_dialog("Connection failed", status_for_dialog, cx);
+                    view.signing_in = false;
+                    match result {
+                        Ok(token) => view.use_token(token, cx),
+                        Err(err) => {
+                            view.clickup_status = format!("Sign-in failed: {err}").into();
+                            view.show_error_dialog("Sign-in failed", format!("{err}"), cx);
+                        }
                     }
                     cx.notify();
                 });
@@ -210,125 +765,3221 @@ impl ClickLiteApp {
         .detach();
     }
 
-    pub fn fetch_channels(&mut self, cx: &mut Context<Self>) {
-        if self.channels_loading {
-            return;
-        }
+    fn show_token_dialog(&mut self, cx: &mut Context<Self>) {
+        let app_entity = cx.entity();
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            use gpui_component::WindowExt as _;
+            use gpui_component::dialog::DialogButtonProps;
+            use gpui_component::input::Input;
 
-        let Some(workspace_id) = self.team_id else {
-            let msg = "Missing CLICKUP_WORKSPACE_ID (or CLICKUP_TEAM_ID) in .env";
-            self.clickup_status = msg.into();
-            self.show_error_dialog("Configuration error", msg, cx);
-            cx.notify();
-            return;
-        };
+            let input = cx.new(|cx| InputState::new(window, cx).masked(true).placeholder("pk_…"));
+            window.open_dialog(cx, move |dialog, _window, _cx| {
+                let input = input.clone();
+                let app_entity = app_entity.clone();
+                dialog
+                    .title("Sign in to ClickUp")
+                    .w(px(440.0))
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .child(div().text_sm().child(
+                                "ClickUp rejected the saved token. Paste a new personal API token.",
+                            ))
+                            .child(Input::new(&input)),
+                    )
+                    .confirm()
+                    .button_props(DialogButtonProps::default().ok_text("Sign in"))
+                    .on_ok(move |_ev, _window, cx| {
+                        let token = input.read(cx).value().trim().to_string();
+                        if token.is_empty() {
+                            return false;
+                        }
+                        app_entity.update(cx, |this, cx| this.use_token(token, cx));
+                        true
+                    })
+            });
+        });
+    }
 
-        let api = match ClickUpApi::from_env() {
+    fn use_token(&mut self, token: String, cx: &mut Context<Self>) {
+        let api = match api_for_token(token.clone()) {
             Ok(api) => api,
             Err(err) => {
-                self.clickup_status = format!("{err}").into();
-                self.show_error_dialog("Configuration error", format!("{err}"), cx);
-                cx.notify();
+                self.show_error_dialog("Sign-in failed", format!("{err}"), cx);
                 return;
             }
         };
 
-        self.channels_loading = true;
-        self.clickup_status = "Loading chats…".into();
-        cx.notify();
-
-        let current_user_id = self.user.as_ref().map(|u| u.id);
-
-        cx.spawn(
-            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
-                let mut cx = cx.clone();
-                async move {
-                    let result = cx
-                        .background_spawn(async move {
-                            api.get_chat_channels(workspace_id, current_user_id)
-                        })
-                        .await;
+        self.token_rejected = false;
+        self.mark_signed_in(cx);
+        self.api = Some(api);
+        self.fetch_clickup_user(cx);
 
+        let credentials = self.credentials.clone();
+        cx.spawn(|this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+            let mut cx = cx.clone();
+            async move {
+                let result = cx
+                    .background_spawn(async move { credentials.save(&token) })
+                    .await;
+                if let Err(err) = result {
                     let _ = this.update(&mut cx, |view, cx| {
-                        view.channels_loading = false;
-                        match result {
-                            Ok(channels) => {
-                                view.channels = channels;
-                                view.clickup_status = "Ready".into();
-                            }
-                            Err(err) => {
-                                let msg = format!("Error: {err}");
-                                view.clickup_status = msg.clone().into();
-                                view.show_error_dialog("Failed to load chats", msg, cx);
-                            }
-                        }
-                        cx.notify();
+                        view.show_error_dialog("Could not save credentials", format!("{err}"), cx);
                     });
                 }
-            },
-        )
+            }
+        })
         .detach();
     }
 
-    pub fn select_channel(&mut self, channel: ClickUpChatChannel, cx: &mut Context<Self>) {
-        self.selected_channel = Some(channel.clone());
-        self.server_messages.clear();
-        self.pending_messages.clear();
-        self.pending_ids.clear();
-        self.set_message_input_placeholder(
-            format!(
-                "Message {}{}",
-                channel.icon_prefix(),
-                channel.display_name()
-            ),
-            cx,
-        );
-        self.fetch_messages(&channel.id, cx);
-        cx.notify();
-    }
+    pub fn fetch_clickup_user(&mut self, cx: &mut Context<Self>) {
+        if self.clickup_loading {
+            return;
+        }
+
+        if self.api.is_none() && !self.settings.signed_out && !self.session_expired {
+            self.load_credentials(cx);
+            return;
+        }
+
+        self.clickup_loading = true;
+        self.clickup_status = "Connecting to ClickUp…".into();
+        cx.notify();
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.clickup_loading = false;
+                self.report_missing_api(&err, cx);
+                return;
+            }
+        };
+
+        cx.spawn(|this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+            let mut cx = cx.clone();
+            async move {
+                let result = cx
+                    .background_spawn(async move { api.get_current_user() })
+                    .await;
+
+                let _ = this.update(&mut cx, |view, cx| {
+                    view.clickup_loading = false;
+                    match result {
+                        Ok(user) => {
+                            view.retry_attempt = 0;
+                            view.clickup_status = format!("Connected as {}", user.username).into();
+                            let cache = view.cache.clone();
+                            let cached_user = user.clone();
+                            cx.background_spawn(async move {
+                                let _ = cache.save_user(&cached_user);
+                            })
+                            .detach();
+                            view.user = Some(user);
+                            view.sync_outbox_messages();
+                            view.flush_outbox(cx);
+                            view.fetch_workspaces(cx);
+                        }
+                        Err(err) => {
+                            if view.handle_api_error(&err, cx) {
+                                if err.is_transient() {
+                                    view.retry_later(Self::fetch_clickup_user, cx);
+                                }
+                            } else {
+                                let status = format!("Connection failed: {err}");
+                                view.clickup_status = status.clone().into();
+                                view.user = None;
+                                view.show_error_dialog("Connection failed", status, cx);
+                            }
+                        }
+                    }
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+
+    pub fn selected_workspace(&self) -> Option<&ClickUpWorkspace> {
+        let team_id = self.team_id?;
+        self.workspaces
+            .iter()
+            .find(|workspace| workspace.id == team_id)
+    }
+
+    pub fn open_new_chat_dialog(&mut self, cx: &mut Context<Self>) {
+        self.with_team_members(Self::show_new_chat_dialog, cx);
+    }
+
+    fn with_team_members(
+        &mut self,
+        then: impl FnOnce(&mut Self, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.team_members.is_empty() {
+            then(self, cx);
+            return;
+        }
+
+        self.team_member_callbacks.push(Box::new(then));
+        self.load_team_members(cx);
+    }
+
+    fn load_team_members(&mut self, cx: &mut Context<Self>) {
+        if self.team_members_loading {
+            return;
+        }
+
+        let Some(workspace_id) = self.team_id else {
+            self.team_member_callbacks.clear();
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.team_member_callbacks.clear();
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        self.team_members_loading = true;
+        let generation = self.workspace_generation;
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn(async move { api.get_team_members(workspace_id) })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        if view.workspace_generation != generation {
+                            if !view.team_member_callbacks.is_empty() {
+                                view.load_team_members(cx);
+                            }
+                            return;
+                        }
+
+                        view.team_members_loading = false;
+                        let callbacks = std::mem::take(&mut view.team_member_callbacks);
+                        match result {
+                            Ok(members) => {
+                                view.team_members = members;
+                                for callback in callbacks {
+                                    callback(view, cx);
+                                }
+                            }
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to load people",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn reset_workspace_loads(&mut self) {
+        self.workspace_generation += 1;
+        self.team_members.clear();
+        self.team_members_loading = false;
+        self.team_member_callbacks.clear();
+        self.task_lists.clear();
+        self.task_lists_loading = false;
+        self.task_list_callbacks.clear();
+    }
+
+    fn show_new_chat_dialog(&mut self, cx: &mut Context<Self>) {
+        let current_user_id = self.user.as_ref().map(|u| u.id);
+        let members: Vec<ClickUpUser> = self
+            .team_members
+            .iter()
+            .filter(|member| Some(member.id) != current_user_id)
+            .cloned()
+            .collect();
+        let app_entity = cx.entity();
+
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            use gpui_component::WindowExt as _;
+            use gpui_component::dialog::DialogButtonProps;
+
+            let form = cx.new(|cx| NewChatForm::new(members, window, cx));
+            window.open_dialog(cx, move |dialog, _window, _cx| {
+                let form = form.clone();
+                let app_entity = app_entity.clone();
+                dialog
+                    .title("New conversation")
+                    .w(px(440.0))
+                    .child(form.clone())
+                    .confirm()
+                    .button_props(DialogButtonProps::default().ok_text("Create"))
+                    .on_ok(move |_ev, _window, cx| {
+                        let Some(request) = form.update(cx, |form, cx| form.request(cx)) else {
+                            return false;
+                        };
+                        app_entity.update(cx, |this, cx| this.create_chat(request, cx));
+                        true
+                    })
+            });
+        });
+    }
+
+    pub fn open_create_task_dialog(&mut self, message_id: String, cx: &mut Context<Self>) {
+        let Some(message) = self
+            .server_messages
+            .iter()
+            .find(|m| m.id == message_id)
+            .cloned()
+        else {
+            return;
+        };
+
+        let description = message.display_content();
+        let name = task_name_from_content(&description);
+        self.with_task_dialog(name, description, Some(message.id), cx);
+    }
+
+    pub fn open_new_task_dialog(&mut self, name: String, cx: &mut Context<Self>) {
+        self.with_task_dialog(name, String::new(), None, cx);
+    }
+
+    fn with_task_dialog(
+        &mut self,
+        name: String,
+        description: String,
+        reply_to: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        self.with_team_members(
+            move |this, cx| {
+                this.with_task_lists(
+                    move |this, cx| this.show_create_task_dialog(name, description, reply_to, cx),
+                    cx,
+                )
+            },
+            cx,
+        );
+    }
+
+    fn with_task_lists(
+        &mut self,
+        then: impl FnOnce(&mut Self, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) {
+        self.task_list_callbacks.push(Box::new(then));
+        self.load_task_lists(cx);
+    }
+
+    fn load_task_lists(&mut self, cx: &mut Context<Self>) {
+        if self.task_lists_loading {
+            return;
+        }
+
+        let Some(workspace_id) = self.team_id else {
+            self.task_list_callbacks.clear();
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.task_list_callbacks.clear();
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        self.task_lists_loading = true;
+        let generation = self.workspace_generation;
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn(async move { api.get_task_lists(workspace_id) })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        if view.workspace_generation != generation {
+                            if !view.task_list_callbacks.is_empty() {
+                                view.load_task_lists(cx);
+                            }
+                            return;
+                        }
+
+                        view.task_lists_loading = false;
+                        let callbacks = std::mem::take(&mut view.task_list_callbacks);
+                        match result {
+                            Ok(lists) => {
+                                view.task_lists = lists;
+                                for callback in callbacks {
+                                    callback(view, cx);
+                                }
+                            }
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to load lists",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                } else if err.is_transient() && !view.task_lists.is_empty() {
+                                    for callback in callbacks {
+                                        callback(view, cx);
+                                    }
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn show_create_task_dialog(
+        &mut self,
+        name: String,
+        description: String,
+        reply_to: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        if self.task_lists.is_empty() {
+            self.show_error_dialog(
+                "No lists available",
+                "Create a list in ClickUp before adding tasks from chat.",
+                cx,
+            );
+            return;
+        }
+
+        let lists = self.task_lists.clone();
+        let selected_list = self.settings.last_task_list_id.clone();
+        let members = self.team_members.clone();
+        let title = if reply_to.is_some() {
+            "Create task from message"
+        } else {
+            "Create task"
+        };
+        let app_entity = cx.entity();
+
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            use gpui_component::WindowExt as _;
+            use gpui_component::dialog::DialogButtonProps;
+
+            let form = cx.new(|cx| {
+                CreateTaskForm::new(lists, selected_list, members, name, description, window, cx)
+            });
+            window.open_dialog(cx, move |dialog, _window, _cx| {
+                let form = form.clone();
+                let app_entity = app_entity.clone();
+                let reply_to = reply_to.clone();
+                dialog
+                    .title(title)
+                    .w(px(480.0))
+                    .child(form.clone())
+                    .confirm()
+                    .button_props(DialogButtonProps::default().ok_text("Create task"))
+                    .on_ok(move |_ev, _window, cx| {
+                        let Some(request) = form.update(cx, |form, cx| form.request(cx)) else {
+                            return false;
+                        };
+                        let reply_to = reply_to.clone();
+                        app_entity.update(cx, |this, cx| this.create_task(request, reply_to, cx));
+                        true
+                    })
+            });
+        });
+    }
+
+    fn create_task(
+        &mut self,
+        request: CreateTaskRequest,
+        reply_to: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        self.settings.last_task_list_id = Some(request.list_id.clone());
+        self.save_settings(cx);
+        self.clickup_status = "Creating task…".into();
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let reply_to = reply_to.clone();
+                            async move {
+                                let task = api.create_task(&request.list_id, &request.task)?;
+                                let reply = reply_to.map(|message_id| {
+                                    let link = format!(
+                                        "Created task [{}]({})",
+                                        task.name.replace(['[', ']'], ""),
+                                        task.url
+                                    );
+                                    api.send_reply(workspace_id, &message_id, &link)
+                                });
+                                Ok::<_, AppError>((task, reply))
+                            }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let (task, reply) = match result {
+                            Ok(created) => created,
+                            Err(err) => {
+                                view.clickup_status = "Task was not created".into();
+                                if !view.handle_api_error(&err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to create task",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                                cx.notify();
+                                return;
+                            }
+                        };
+
+                        view.clickup_status = format!("Created task {}", task.name).into();
+                        let task_ref = TaskRef::Id(task.id.clone());
+                        view.learn_task_prefix(&task, cx);
+                        view.task_previews_requested.insert(task_ref.clone());
+                        view.task_previews.insert(task_ref, task);
+
+                        let Some(message_id) = reply_to else {
+                            cx.notify();
+                            return;
+                        };
+                        match reply {
+                            Some(Ok(reply)) => {
+                                if let Some(parent) =
+                                    view.server_messages.iter_mut().find(|m| m.id == message_id)
+                                {
+                                    parent.replies_count += 1;
+                                }
+                                if view
+                                    .thread_parent
+                                    .as_ref()
+                                    .is_some_and(|parent| parent.id == message_id)
+                                    && !view.thread_replies.iter().any(|m| m.id == reply.id)
+                                {
+                                    view.thread_replies.push(reply);
+                                }
+                            }
+                            Some(Err(err)) => {
+                                view.show_error_dialog(
+                                    "Task created, but the link was not posted",
+                                    format!("{err}"),
+                                    cx,
+                                );
+                            }
+                            None => {}
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn confirm_action(
+        &self,
+        title: impl Into<SharedString>,
+        message: impl Into<SharedString>,
+        ok_text: impl Into<SharedString>,
+        on_ok: impl Fn(&mut Self, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) {
+        let title: SharedString = title.into();
+        let message: SharedString = message.into();
+        let ok_text: SharedString = ok_text.into();
+        let on_ok = std::rc::Rc::new(on_ok);
+        let app_entity = cx.entity();
+
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            use gpui_component::WindowExt as _;
+            use gpui_component::dialog::DialogButtonProps;
+
+            window.open_dialog(cx, move |dialog, _window, _cx| {
+                let app_entity = app_entity.clone();
+                let on_ok = on_ok.clone();
+                dialog
+                    .title(title.clone())
+                    .child(div().text_sm().child(message.clone()))
+                    .confirm()
+                    .button_props(DialogButtonProps::default().ok_text(ok_text.clone()))
+                    .on_ok(move |_ev, _window, cx| {
+                        app_entity.update(cx, |this, cx| on_ok(this, cx));
+                        true
+                    })
+            });
+        });
+    }
+
+    pub fn toggle_channel_details(&mut self, cx: &mut Context<Self>) {
+        if self.channel_details_open {
+            self.close_channel_details(cx);
+            return;
+        }
+
+        if self.selected_channel.is_none() {
+            return;
+        }
+
+        self.close_thread(cx);
+        self.close_search(cx);
+        self.channel_details_open = true;
+        self.fetch_channel_members(cx);
+        cx.notify();
+    }
+
+    pub fn close_channel_details(&mut self, cx: &mut Context<Self>) {
+        if !self.channel_details_open {
+            return;
+        }
+
+        self.channel_details_open = false;
+        cx.notify();
+    }
+
+    fn fetch_channel_members(&mut self, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(_) => return,
+        };
+
+        self.channel_members_loading = true;
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let channel_id = channel_id.clone();
+                            async move { api.get_channel_members(workspace_id, &channel_id) }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let still_selected = view
+                            .selected_channel
+                            .as_ref()
+                            .is_some_and(|channel| channel.id == channel_id);
+                        if !still_selected {
+                            return;
+                        }
+
+                        view.channel_members_loading = false;
+                        match result {
+                            Ok(members) => {
+                                view.channel_members = members;
+                                view.cache_members(cx);
+                            }
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to load members",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn open_add_members_dialog(&mut self, cx: &mut Context<Self>) {
+        self.with_team_members(Self::show_add_members_dialog, cx);
+    }
+
+    fn show_add_members_dialog(&mut self, cx: &mut Context<Self>) {
+        let existing: HashSet<&str> = self
+            .channel_members
+            .iter()
+            .map(|member| member.id.as_str())
+            .collect();
+        let candidates: Vec<ClickUpUser> = self
+            .team_members
+            .iter()
+            .filter(|member| !existing.contains(member.id.to_string().as_str()))
+            .cloned()
+            .collect();
+        let app_entity = cx.entity();
+
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            use gpui_component::WindowExt as _;
+            use gpui_component::dialog::DialogButtonProps;
+
+            let picker = cx.new(|cx| PeoplePicker::new(candidates, window, cx));
+            window.open_dialog(cx, move |dialog, _window, _cx| {
+                let picker = picker.clone();
+                let app_entity = app_entity.clone();
+                dialog
+                    .title("Add members")
+                    .w(px(400.0))
+                    .child(picker.clone())
+                    .confirm()
+                    .button_props(DialogButtonProps::default().ok_text("Add"))
+                    .on_ok(move |_ev, _window, cx| {
+                        let user_ids = picker.read(cx).selected_ids();
+                        if user_ids.is_empty() {
+                            return false;
+                        }
+                        app_entity.update(cx, |this, cx| this.add_channel_members(user_ids, cx));
+                        true
+                    })
+            });
+        });
+    }
+
+    fn add_channel_members(&mut self, user_ids: Vec<u64>, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn(async move {
+                            api.add_channel_members(workspace_id, &channel_id, &user_ids)
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        match result {
+                            Ok(()) => view.fetch_channel_members(cx),
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to add members",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn confirm_remove_member(&mut self, member: ChannelMember, cx: &mut Context<Self>) {
+        let name = member.display_name();
+        self.confirm_action(
+            "Remove member",
+            format!("Remove {name} from this channel?"),
+            "Remove",
+            move |this, cx| this.remove_channel_member(member.id.clone(), cx),
+            cx,
+        );
+    }
+
+    pub fn confirm_leave_channel(&mut self, cx: &mut Context<Self>) {
+        let Some(user_id) = self.user.as_ref().map(|u| u.id.to_string()) else {
+            return;
+        };
+
+        self.confirm_action(
+            "Leave channel",
+            "You will stop receiving messages from this channel.",
+            "Leave",
+            move |this, cx| this.remove_channel_member(user_id.clone(), cx),
+            cx,
+        );
+    }
+
+    pub fn confirm_unfollow_channel(&mut self, cx: &mut Context<Self>) {
+        self.confirm_action(
+            "Unfollow channel",
+            "The channel will be hidden from your chat list until you follow it again.",
+            "Unfollow",
+            |this, cx| this.unfollow_channel(cx),
+            cx,
+        );
+    }
+
+    fn remove_channel_member(&mut self, user_id: String, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        let leaving = self
+            .user
+            .as_ref()
+            .is_some_and(|user| user.id.to_string() == user_id);
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let channel_id = channel_id.clone();
+                            let user_id = user_id.clone();
+                            async move {
+                                api.remove_channel_member(workspace_id, &channel_id, &user_id)
+                            }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        match result {
+                            Ok(()) if leaving => view.remove_channel(&channel_id, cx),
+                            Ok(()) => view.channel_members.retain(|m| m.id != user_id),
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to update membership",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn unfollow_channel(&mut self, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let Some(user_id) = self.user.as_ref().map(|u| u.id.to_string()) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let channel_id = channel_id.clone();
+                            async move { api.unfollow_channel(workspace_id, &channel_id, &user_id) }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        match result {
+                            Ok(()) => view.remove_channel(&channel_id, cx),
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to unfollow channel",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn create_chat(&mut self, request: NewChatRequest, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let request = request.clone();
+                            async move {
+                                match request {
+                                    NewChatRequest::DirectMessage { user_ids, .. } => {
+                                        api.create_direct_message(workspace_id, &user_ids)
+                                    }
+                                    NewChatRequest::Channel {
+                                        name,
+                                        private,
+                                        user_ids,
+                                    } => {
+                                        api.create_channel(workspace_id, &name, private, &user_ids)
+                                    }
+                                }
+                            }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        if view.team_id != Some(workspace_id) {
+                            return;
+                        }
+
+                        match result {
+                            Ok(mut channel) => {
+                                if channel.name.as_deref().is_none_or(str::is_empty)
+                                    && let NewChatRequest::DirectMessage { name, .. } = request
+                                {
+                                    channel.name = Some(name);
+                                }
+
+                                let channel =
+                                    match view.channels.iter().find(|c| c.id == channel.id) {
+                                        Some(existing) => existing.clone(),
+                                        None => {
+                                            view.channels.insert(0, channel.clone());
+                                            channel
+                                        }
+                                    };
+                                view.select_channel(channel, cx);
+                            }
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to start conversation",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn fetch_workspaces(&mut self, cx: &mut Context<Self>) {
+        if self.workspaces_loading {
+            return;
+        }
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.clickup_status = format!("{err}").into();
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                cx.notify();
+                return;
+            }
+        };
+
+        self.workspaces_loading = true;
+        self.clickup_status = "Loading workspaces…".into();
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn(async move { api.get_workspaces() })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.workspaces_loading = false;
+                        match result {
+                            Ok(workspaces) => {
+                                view.workspaces = workspaces;
+                                let known = view.selected_workspace().is_some();
+                                if !known && let Some(first) = view.workspaces.first().map(|w| w.id)
+                                {
+                                    view.switch_workspace(first, cx);
+                                } else {
+                                    view.fetch_channels(cx);
+                                }
+                            }
+                            Err(err) => {
+                                if !view.handle_api_error(&err, cx) {
+                                    view.fetch_channels(cx);
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn switch_workspace(&mut self, workspace_id: u64, cx: &mut Context<Self>) {
+        if self.team_id == Some(workspace_id) && !self.channels.is_empty() {
+            return;
+        }
+
+        self.team_id = Some(workspace_id);
+        self.settings.last_workspace_id = Some(workspace_id);
+        self.save_settings(cx);
+
+        self.channels.clear();
+        self.unread_counts.clear();
+        self.reset_workspace_loads();
+        self.task_previews.clear();
+        self.task_previews_requested.clear();
+        self.task_preview_queue.clear();
+        self.channels_loading = false;
+        self.channels_loading_more = false;
+        self.selected_channel = None;
+        self.server_messages.clear();
+        self.sync_outbox_messages();
+        self.history_cursor = None;
+        self.reset_search_index();
+        self.refresh_search_results();
+        self.channels = self.cache.load_channels(workspace_id).unwrap_or_default();
+        self.set_message_input_placeholder("Select a chat to start messaging...", cx);
+        self.fetch_channels(cx);
+        cx.notify();
+    }
+
+    pub fn fetch_channels(&mut self, cx: &mut Context<Self>) {
+        if self.channels_loading || self.channels_loading_more {
+            return;
+        }
+
+        let Some(workspace_id) = self.team_id else {
+            let msg = "No ClickUp workspace selected";
+            self.clickup_status = msg.into();
+            self.show_error_dialog("Configuration error", msg, cx);
+            cx.notify();
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.clickup_status = format!("{err}").into();
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                cx.notify();
+                return;
+            }
+        };
+
+        self.channels_loading = true;
+        self.channels_generation += 1;
+        let generation = self.channels_generation;
+        self.clickup_status = "Loading chats…".into();
+        cx.notify();
+
+        let current_user_id = self.user.as_ref().map(|u| u.id);
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let mut cursor: Option<String> = None;
+                    let mut cursors = PageCursors::default();
+
+                    loop {
+                        let api = api.clone();
+                        let page_cursor = cursor.clone();
+                        let result: Result<ChatChannelsPage, _> = cx
+                            .background_spawn(async move {
+                                api.get_chat_channels_page(
+                                    workspace_id,
+                                    current_user_id,
+                                    page_cursor.as_deref(),
+                                )
+                            })
+                            .await;
+
+                        let is_first_page = cursor.is_none();
+                        let next_cursor = this
+                            .update(&mut cx, |view, cx| {
+                                if view.channels_generation != generation {
+                                    return None;
+                                }
+
+                                view.channels_loading = false;
+                                let next_cursor = match result {
+                                    Ok(page) => {
+                                        let next_cursor =
+                                            cursors.advance(page.next_cursor, page.channels.len());
+                                        if is_first_page {
+                                            view.channels = page.channels;
+                                        } else {
+                                            view.channels.extend(page.channels);
+                                        }
+                                        view.retry_attempt = 0;
+                                        view.clickup_status = "Ready".into();
+                                        if next_cursor.is_none() {
+                                            view.cache_channels(cx);
+                                        }
+                                        next_cursor
+                                    }
+                                    Err(err) => {
+                                        if view.handle_api_error(&err, cx) {
+                                            if err.is_transient() {
+                                                view.retry_later(Self::fetch_channels, cx);
+                                            }
+                                        } else {
+                                            let msg = format!("Error: {err}");
+                                            view.clickup_status = msg.clone().into();
+                                            view.show_error_dialog("Failed to load chats", msg, cx);
+                                        }
+                                        None
+                                    }
+                                };
+                                view.channels_loading_more = next_cursor.is_some();
+                                if !view.channels_loading_more && !view.channels.is_empty() {
+                                    view.fetch_unread_counts(false, cx);
+                                }
+                                cx.notify();
+                                next_cursor
+                            })
+                            .ok()
+                            .flatten();
+
+                        match next_cursor {
+                            Some(next) => cursor = Some(next),
+                            None => break,
+                        }
+                    }
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn is_channel_unread(&self, channel: &ClickUpChatChannel) -> bool {
+        let Some(latest) = channel.latest_comment_at else {
+            return false;
+        };
+        let last_read = self
+            .settings
+            .last_read
+            .get(&channel.id)
+            .copied()
+            .or(self.settings.unread_since)
+            .unwrap_or(0);
+        latest > last_read
+    }
+
+    pub fn unread_count(&self, channel_id: &str) -> Option<usize> {
+        self.unread_counts.get(channel_id).copied()
+    }
+
+    pub fn unread_channel_count(&self) -> usize {
+        self.channels
+            .iter()
+            .filter(|channel| self.is_channel_unread(channel))
+            .count()
+    }
+
+    fn is_scrolled_to_bottom(&self) -> bool {
+        let max_offset = self.scroll_handle.max_offset().height;
+        max_offset <= px(0.) || self.scroll_handle.offset().y + max_offset <= px(24.)
+    }
+
+    fn mark_selected_channel_read(&mut self, window: &Window, cx: &mut Context<Self>) {
+        if !window.is_window_active() || self.messages_loading || !self.is_scrolled_to_bottom() {
+            return;
+        }
+
+        let Some(channel) = self.selected_channel.as_ref() else {
+            return;
+        };
+        let newest_message_at = self.server_messages.iter().filter_map(|m| m.date).max();
+        let Some(read_at) = channel.latest_comment_at.max(newest_message_at) else {
+            return;
+        };
+
+        let channel_id = channel.id.clone();
+        self.unread_counts.remove(&channel_id);
+        self.set_last_read(channel_id, read_at, cx);
+    }
+
+    fn set_last_read(&mut self, channel_id: String, read_at: u64, cx: &mut Context<Self>) {
+        if self
+            .settings
+            .last_read
+            .get(&channel_id)
+            .is_some_and(|last_read| *last_read >= read_at)
+        {
+            return;
+        }
+
+        self.settings.last_read.insert(channel_id, read_at);
+        self.save_settings(cx);
+    }
+
+    pub fn refresh_channel_activity(&mut self, cx: &mut Context<Self>) {
+        if self.channel_activity_refreshing || self.channels_loading || self.channels_loading_more {
+            return;
+        }
+
+        if self
+            .refresh_paused_until
+            .is_some_and(|until| Instant::now() < until)
+            || self.rate_limit_low()
+        {
+            return;
+        }
+
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(_) => return,
+        };
+
+        self.channel_activity_refreshing = true;
+        let current_user_id = self.user.as_ref().map(|u| u.id);
+        let known_ids: HashSet<String> = self.channels.iter().map(|c| c.id.clone()).collect();
+        let since = self
+            .channels
+            .iter()
+            .filter_map(|channel| channel.latest_comment_at)
+            .max();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn(async move {
+                            api.get_channel_activity(
+                                workspace_id,
+                                current_user_id,
+                                &known_ids,
+                                since,
+                            )
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.channel_activity_refreshing = false;
+                        if view.team_id != Some(workspace_id) {
+                            return;
+                        }
+
+                        match result {
+                            Ok(activity) => {
+                                if !activity.is_empty() {
+                                    view.apply_channel_activity(activity);
+                                    view.cache_channels(cx);
+                                }
+                                view.flush_outbox(cx);
+                                view.fetch_unread_counts(true, cx);
+                            }
+                            Err(err) => {
+                                view.handle_api_error(&err, cx);
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn apply_channel_activity(&mut self, activity: Vec<ClickUpChatChannel>) {
+        let mut updated = Vec::new();
+        for channel in activity {
+            let current = match self.channels.iter().position(|c| c.id == channel.id) {
+                Some(ix) => {
+                    let mut current = self.channels.remove(ix);
+                    if current.latest_comment_at != channel.latest_comment_at {
+                        self.unread_counts.remove(&channel.id);
+                    }
+                    current.latest_comment_at = channel.latest_comment_at;
+                    if channel.name.is_some() {
+                        current.name = channel.name;
+                    }
+                    current
+                }
+                None => channel,
+            };
+            updated.push(current);
+        }
+
+        if let Some(selected) = self.selected_channel.as_mut()
+            && let Some(current) = updated.iter().find(|channel| channel.id == selected.id)
+        {
+            selected.latest_comment_at = current.latest_comment_at;
+        }
+
+        updated.sort_by_key(|channel| std::cmp::Reverse(channel.latest_comment_at));
+        updated.append(&mut self.channels);
+        self.channels = updated;
+    }
+
+    fn fetch_unread_counts(&mut self, notify: bool, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let selected_id = self.selected_channel.as_ref().map(|c| c.id.clone());
+        let pending: Vec<(String, u64, u64)> = self
+            .channels
+            .iter()
+            .filter(|channel| {
+                self.is_channel_unread(channel)
+                    && !self.unread_counts.contains_key(&channel.id)
+                    && selected_id.as_deref() != Some(channel.id.as_str())
+            })
+            .map(|channel| {
+                let since = self
+                    .settings
+                    .last_read
+                    .get(&channel.id)
+                    .copied()
+                    .or(self.settings.unread_since)
+                    .unwrap_or(0);
+                let latest = channel.latest_comment_at.unwrap_or(since);
+                (channel.id.clone(), since, latest)
+            })
+            .take(UNREAD_COUNT_BATCH)
+            .collect();
+
+        if pending.is_empty() {
+            return;
+        }
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(_) => return,
+        };
+        let current_user_id = self.user.as_ref().map(|u| u.id.to_string());
+        let cache = self.cache.clone();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let summaries: Vec<UnreadSummary> = cx
+                        .background_spawn(async move {
+                            pending
+                                .into_iter()
+                                .filter_map(|(channel_id, since, latest)| {
+                                    let page = api
+                                        .request_channel_messages_page(
+                                            workspace_id,
+                                            &channel_id,
+                                            None,
+                                        )
+                                        .ok()?;
+                                    let unread: Vec<ChatMessage> = page
+                                        .messages
+                                        .into_iter()
+                                        .filter(|m| m.date.is_some_and(|date| date > since))
+                                        .filter(|m| m.user_id != current_user_id)
+                                        .collect();
+                                    let mut newest = unread.iter().max_by_key(|m| m.date).cloned();
+                                    let mut mention =
+                                        current_user_id.as_deref().and_then(|user_id| {
+                                            unread
+                                                .iter()
+                                                .filter(|m| m.mentions_user(user_id))
+                                                .max_by_key(|m| m.date)
+                                                .cloned()
+                                        });
+                                    if let Some(members) =
+                                        cache.load_members(workspace_id, &channel_id)
+                                    {
+                                        for message in newest.iter_mut().chain(mention.iter_mut()) {
+                                            resolve_message_creators(
+                                                std::slice::from_mut(message),
+                                                &members,
+                                            );
+                                        }
+                                    }
+                                    Some(UnreadSummary {
+                                        channel_id,
+                                        latest,
+                                        count: unread.len(),
+                                        newest,
+                                        mention,
+                                    })
+                                })
+                                .collect()
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        if view.team_id != Some(workspace_id) {
+                            return;
+                        }
+
+                        for summary in summaries {
+                            if summary.count == 0 {
+                                view.set_last_read(summary.channel_id, summary.latest, cx);
+                                continue;
+                            }
+
+                            view.unread_counts
+                                .insert(summary.channel_id.clone(), summary.count);
+                            if notify {
+                                view.notify_unread(&summary, cx);
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn start_notifications(&mut self, cx: &mut Context<Self>) {
+        cx.spawn(|this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+            let mut cx = cx.clone();
+            async move {
+                let Ok(notifier) = DesktopNotifier::connect().await else {
+                    return;
+                };
+                let clicks = notifier.clicks().await;
+                if this
+                    .update(&mut cx, |view, _cx| view.notifier = Some(notifier))
+                    .is_err()
+                {
+                    return;
+                }
+
+                let Ok(mut clicks) = clicks else {
+                    return;
+                };
+                while let Some(notification_id) = clicks.next().await {
+                    if this
+                        .update(&mut cx, |view, cx| {
+                            view.open_notification(notification_id, cx);
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }
+        })
+        .detach();
+    }
+
+    fn open_notification(&mut self, notification_id: u32, cx: &mut Context<Self>) {
+        let Some(channel_id) = self.notification_targets.remove(&notification_id) else {
+            return;
+        };
+
+        let _ = cx.update_window(self.window_handle, |_, window, _cx| {
+            window.activate_window();
+        });
+
+        let already_selected = self
+            .selected_channel
+            .as_ref()
+            .is_some_and(|channel| channel.id == channel_id);
+        if !already_selected
+            && let Some(channel) = self.channels.iter().find(|c| c.id == channel_id).cloned()
+        {
+            self.select_channel(channel, cx);
+        }
+    }
+
+    fn is_window_active(&self, cx: &mut Context<Self>) -> bool {
+        cx.update_window(self.window_handle, |_, window, _cx| {
+            window.is_window_active()
+        })
+        .unwrap_or(false)
+    }
+
+    pub fn do_not_disturb(&self) -> bool {
+        self.settings.do_not_disturb
+    }
+
+    pub fn toggle_do_not_disturb(&mut self, cx: &mut Context<Self>) {
+        self.settings.do_not_disturb = !self.settings.do_not_disturb;
+        self.save_settings(cx);
+        cx.notify();
+    }
+
+    pub fn is_channel_muted(&self, channel_id: &str) -> bool {
+        self.settings.muted_channels.contains(channel_id)
+    }
+
+    pub fn toggle_channel_muted(&mut self, channel_id: String, cx: &mut Context<Self>) {
+        if !self.settings.muted_channels.remove(&channel_id) {
+            self.settings.muted_channels.insert(channel_id);
+        }
+        self.save_settings(cx);
+        cx.notify();
+    }
+
+    fn notify_unread(&mut self, summary: &UnreadSummary, cx: &mut Context<Self>) {
+        if self.is_window_active(cx) {
+            return;
+        }
+
+        let Some(channel) = self.channels.iter().find(|c| c.id == summary.channel_id) else {
+            return;
+        };
+        let channel_name = format!("{}{}", channel.icon_prefix(), channel.display_name());
+
+        let (title, body) = if let Some(ref message) = summary.mention {
+            (
+                format!("{} mentioned you in {channel_name}", message.creator_name()),
+                message.preview_text(),
+            )
+        } else if let Some(ref message) = summary.newest {
+            let title = match summary.count {
+                1 => channel_name,
+                count => format!("{channel_name} ({count} new messages)"),
+            };
+            (
+                title,
+                format!("{}: {}", message.creator_name(), message.preview_text()),
+            )
+        } else {
+            return;
+        };
+
+        let channel_id = summary.channel_id.clone();
+        self.send_notification(channel_id, title, body, cx);
+    }
+
+    fn notify_selected_mentions(&mut self, page: &ChatMessagesPage, cx: &mut Context<Self>) {
+        if self.server_messages.is_empty() || self.is_window_active(cx) {
+            return;
+        }
+
+        let (Some(channel), Some(user)) = (self.selected_channel.as_ref(), self.user.as_ref())
+        else {
+            return;
+        };
+        let user_id = user.id.to_string();
+        let known_ids: HashSet<&str> = self.server_messages.iter().map(|m| m.id.as_str()).collect();
+        let Some(message) = page.messages.iter().rev().find(|m| {
+            !known_ids.contains(m.id.as_str())
+                && m.user_id.as_deref() != Some(user_id.as_str())
+                && m.mentions_user(&user_id)
+        }) else {
+            return;
+        };
+
+        let title = format!(
+            "{} mentioned you in {}{}",
+            message.creator_name(),
+            channel.icon_prefix(),
+            channel.display_name()
+        );
+        let body = message.preview_text();
+        let channel_id = channel.id.clone();
+        self.send_notification(channel_id, title, body, cx);
+    }
+
+    fn send_notification(
+        &mut self,
+        channel_id: String,
+        title: String,
+        body: String,
+        cx: &mut Context<Self>,
+    ) {
+        if self.settings.do_not_disturb || self.is_channel_muted(&channel_id) {
+            return;
+        }
+
+        let Some(notifier) = self.notifier.clone() else {
+            return;
+        };
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    if let Ok(notification_id) = notifier.notify(&title, &body).await {
+                        let _ = this.update(&mut cx, |view, _cx| {
+                            view.notification_targets
+                                .insert(notification_id, channel_id);
+                        });
+                    }
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn cache_channels(&self, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let cache = self.cache.clone();
+        let channels = self.channels.clone();
+        cx.background_spawn(async move {
+            let _ = cache.save_channels(workspace_id, &channels);
+        })
+        .detach();
+    }
+
+    fn cache_messages(&self, cx: &mut Context<Self>) {
+        let (Some(workspace_id), Some(channel)) = (self.team_id, self.selected_channel.as_ref())
+        else {
+            return;
+        };
+
+        let cache = self.cache.clone();
+        let channel_id = channel.id.clone();
+        let messages = self.server_messages.clone();
+        cx.background_spawn(async move {
+            let _ = cache.save_messages(workspace_id, &channel_id, &messages);
+        })
+        .detach();
+    }
+
+    fn cache_members(&self, cx: &mut Context<Self>) {
+        let (Some(workspace_id), Some(channel)) = (self.team_id, self.selected_channel.as_ref())
+        else {
+            return;
+        };
+
+        let cache = self.cache.clone();
+        let channel_id = channel.id.clone();
+        let members = self.channel_members.clone();
+        cx.background_spawn(async move {
+            let _ = cache.save_members(workspace_id, &channel_id, &members);
+        })
+        .detach();
+    }
+
+    pub fn open_search(&mut self, scope: SearchScope, cx: &mut Context<Self>) {
+        let scope = if self.selected_channel.is_none() {
+            SearchScope::Workspace
+        } else {
+            scope
+        };
+
+        self.close_thread(cx);
+        self.close_channel_details(cx);
+        self.search_open = true;
+        self.set_search_scope(scope, cx);
+
+        let input = self.search_input.clone();
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            input.update(cx, |state, cx| state.focus(window, cx));
+        });
+    }
+
+    pub fn set_search_scope(&mut self, scope: SearchScope, cx: &mut Context<Self>) {
+        if scope == SearchScope::Channel && self.selected_channel.is_none() {
+            return;
+        }
+
+        self.search_scope = scope;
+        if scope == SearchScope::Workspace {
+            self.load_cached_search_index(cx);
+        }
+        self.refresh_search_results();
+        cx.notify();
+    }
+
+    pub fn close_search(&mut self, cx: &mut Context<Self>) {
+        if !self.search_open {
+            return;
+        }
+
+        self.search_open = false;
+        self.highlighted_message_id = None;
+        self.scroll_target_id = None;
+        cx.notify();
+    }
+
+    pub fn open_search_result(&mut self, hit: SearchHit, cx: &mut Context<Self>) {
+        let already_selected = self
+            .selected_channel
+            .as_ref()
+            .is_some_and(|channel| channel.id == hit.channel_id);
+        if !already_selected {
+            let Some(channel) = self
+                .channels
+                .iter()
+                .find(|channel| channel.id == hit.channel_id)
+                .cloned()
+            else {
+                self.clickup_status = "That chat is no longer available".into();
+                cx.notify();
+                return;
+            };
+            self.select_channel(channel, cx);
+        }
+
+        self.highlighted_message_id = Some(hit.message_id.clone());
+        self.scroll_target_id = Some(hit.message_id);
+        self.scroll_to_search_target(cx);
+        cx.notify();
+    }
+
+    fn index_loaded_messages(&mut self) {
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        self.search_index
+            .index_messages(&channel_id, &self.server_messages);
+        self.refresh_search_results();
+    }
+
+    pub fn search_for(&mut self, scope: SearchScope, query: String, cx: &mut Context<Self>) {
+        self.open_search(scope, cx);
+
+        let input = self.search_input.clone();
+        let value = query.clone();
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            input.update(cx, |state, cx| state.set_value(value, window, cx));
+        });
+        self.search_query = query;
+        self.refresh_search_results();
+        cx.notify();
+    }
+
+    fn reset_search_index(&mut self) {
+        self.search_index.clear();
+        self.search_cache_loaded = false;
+    }
+
+    fn refresh_search_results(&mut self) {
+        if !self.search_open {
+            return;
+        }
+
+        let channel_id = match self.search_scope {
+            SearchScope::Channel => self.selected_channel.as_ref().map(|c| c.id.as_str()),
+            SearchScope::Workspace => None,
+        };
+        self.search_results =
+            self.search_index
+                .search(&self.search_query, channel_id, SEARCH_RESULT_LIMIT);
+    }
+
+    fn load_cached_search_index(&mut self, cx: &mut Context<Self>) {
+        if self.search_cache_loaded {
+            return;
+        }
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        self.search_cache_loaded = true;
+        let cache = self.cache.clone();
+        let channel_ids: Vec<String> = self.channels.iter().map(|c| c.id.clone()).collect();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let cached: Vec<(String, Vec<ChatMessage>)> = cx
+                        .background_spawn(async move {
+                            channel_ids
+                                .into_iter()
+                                .filter_map(|channel_id| {
+                                    let messages =
+                                        cache.load_messages(workspace_id, &channel_id)?;
+                                    Some((channel_id, messages))
+                                })
+                                .collect()
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        if view.team_id != Some(workspace_id) {
+                            return;
+                        }
+
+                        for (channel_id, messages) in cached {
+                            view.search_index.index_messages(&channel_id, &messages);
+                        }
+                        view.index_loaded_messages();
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn scroll_to_search_target(&mut self, cx: &mut Context<Self>) {
+        if self.messages_loading || self.history_loading {
+            return;
+        }
+        let Some(target) = self.scroll_target_id.clone() else {
+            return;
+        };
+
+        let target_ix = self.messages().position(|message| message.id == target);
+        if let Some(ix) = target_ix {
+            self.scroll_target_id = None;
+            self.history_scroll_anchor = None;
+            self.scroll_handle.scroll_to_top_of_item(ix);
+        } else if self.history_cursor.is_some() {
+            self.load_older_messages(cx);
+        } else {
+            self.scroll_target_id = None;
+            self.highlighted_message_id = None;
+            self.clickup_status = "That message is no longer available".into();
+        }
+    }
+
+    pub fn select_channel(&mut self, channel: ClickUpChatChannel, cx: &mut Context<Self>) {
+        self.selected_channel = Some(channel.clone());
+        self.server_messages.clear();
+        self.sync_outbox_messages();
+        self.history_cursor = None;
+        self.history_loading = false;
+        self.history_scroll_anchor = None;
+        self.highlighted_message_id = None;
+        self.scroll_target_id = None;
+        self.index_loaded_messages();
+        self.load_message_media(cx);
+        if self.editing_message_id.take().is_some() {
+            self.clear_message_input(cx);
+        }
+        self.close_thread(cx);
+        self.channel_members.clear();
+        if self.channel_details_open {
+            self.fetch_channel_members(cx);
+        }
+        self.dismiss_mentions(cx);
+        self.mention_members.clear();
+        self.draft_mentions.clear();
+        self.restore_message_input_placeholder(cx);
+        self.scroll_to_bottom();
+        self.fetch_messages(&channel.id, cx);
+        self.fetch_mention_members(&channel.id, cx);
+        self.load_cached_channel(channel.id, cx);
+        cx.notify();
+    }
+
+    fn load_cached_channel(&mut self, channel_id: String, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let cache = self.cache.clone();
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let request_channel_id = channel_id.clone();
+                    let (messages, members) = cx
+                        .background_spawn(async move {
+                            (
+                                cache.load_messages(workspace_id, &request_channel_id),
+                                cache.load_members(workspace_id, &request_channel_id),
+                            )
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let still_selected = view
+                            .selected_channel
+                            .as_ref()
+                            .is_some_and(|channel| channel.id == channel_id);
+                        if !still_selected || view.team_id != Some(workspace_id) {
+                            return;
+                        }
+
+                        if let Some(messages) = messages
+                            && view.messages_loading
+                            && view.server_messages.is_empty()
+                        {
+                            view.server_messages = messages;
+                            view.index_loaded_messages();
+                            view.load_message_media(cx);
+                            view.scroll_to_bottom();
+                        }
+                        if let Some(members) = members
+                            && view.channel_members.is_empty()
+                        {
+                            if view.mention_members.is_empty() {
+                                view.mention_members = members.clone();
+                            }
+                            view.channel_members = members;
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
 
     pub fn refresh_messages(&mut self, cx: &mut Context<Self>) {
+        if let Some(until) = self.refresh_paused_until {
+            if Instant::now() < until {
+                return;
+            }
+            self.refresh_paused_until = None;
+        }
+
+        if self.rate_limit_low() {
+            return;
+        }
+
         if let Some(ref channel) = self.selected_channel {
             let channel_id = channel.id.clone();
             self.fetch_messages_silent(&channel_id, cx);
         }
     }
 
-    fn fetch_messages_silent(&mut self, channel_id: &str, cx: &mut Context<Self>) {
+    fn fetch_messages_silent(&mut self, channel_id: &str, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(_) => return,
+        };
+
+        let channel_id = channel_id.to_string();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let request_channel_id = channel_id.clone();
+                    let result: Result<ChatMessagesPage, _> = cx
+                        .background_spawn(async move {
+                            api.get_channel_messages_page(workspace_id, &request_channel_id, None)
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let still_selected = view.team_id == Some(workspace_id)
+                            && view
+                                .selected_channel
+                                .as_ref()
+                                .is_some_and(|channel| channel.id == channel_id);
+                        if !still_selected {
+                            return;
+                        }
+
+                        let mut page = match result {
+                            Ok(page) => page,
+                            Err(err) => {
+                                if !view.handle_api_error(&err, cx) && err.is_not_found() {
+                                    view.channel_not_found(&channel_id, cx);
+                                }
+                                cx.notify();
+                                return;
+                            }
+                        };
+
+                        page.messages.reverse();
+
+                        view.flush_outbox(cx);
+
+                        view.notify_selected_mentions(&page, cx);
+                        if view.merge_latest_page(page) {
+                            view.index_loaded_messages();
+                            view.load_message_media(cx);
+                            view.cache_messages(cx);
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn fetch_messages(&mut self, channel_id: &str, cx: &mut Context<Self>) {
+        if self.messages_loading {
+            return;
+        }
+
+        let Some(workspace_id) = self.team_id else {
+            self.show_error_dialog(
+                "Configuration error",
+                "Missing CLICKUP_WORKSPACE_ID (or CLICKUP_TEAM_ID) in .env",
+                cx,
+            );
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        self.messages_loading = true;
+        cx.notify();
+
+        let channel_id = channel_id.to_string();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let request_channel_id = channel_id.clone();
+                    let result: Result<ChatMessagesPage, _> = cx
+                        .background_spawn(async move {
+                            api.get_channel_messages_page(workspace_id, &request_channel_id, None)
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.messages_loading = false;
+                        let still_selected = view
+                            .selected_channel
+                            .as_ref()
+                            .is_some_and(|channel| channel.id == channel_id);
+                        if !still_selected {
+                            cx.notify();
+                            return;
+                        }
+
+                        match result {
+                            Ok(mut page) => {
+                                view.retry_attempt = 0;
+                                page.messages.reverse();
+                                view.server_messages = page.messages;
+                                view.history_cursor = page.next_cursor;
+                                view.index_loaded_messages();
+                                view.load_message_media(cx);
+                                if view.highlighted_message_id.is_some() {
+                                    view.scroll_target_id = view.highlighted_message_id.clone();
+                                    view.scroll_to_search_target(cx);
+                                } else {
+                                    view.scroll_to_bottom();
+                                }
+                                view.cache_messages(cx);
+                            }
+                            Err(err) => {
+                                if view.handle_api_error(&err, cx) {
+                                    if err.is_transient() {
+                                        view.retry_later(
+                                            move |view, cx| {
+                                                let still_selected =
+                                                    view.selected_channel.as_ref().is_some_and(
+                                                        |channel| channel.id == channel_id,
+                                                    );
+                                                if still_selected {
+                                                    view.fetch_messages(&channel_id, cx);
+                                                }
+                                            },
+                                            cx,
+                                        );
+                                    }
+                                } else if err.is_not_found() {
+                                    view.channel_not_found(&channel_id, cx);
+                                } else {
+                                    view.show_error_dialog(
+                                        "Failed to load messages",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn load_older_messages(&mut self, cx: &mut Context<Self>) {
+        if self.history_loading || self.messages_loading {
+            return;
+        }
+
+        let Some(cursor) = self.history_cursor.clone() else {
+            return;
+        };
+
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        self.history_loading = true;
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let request_channel_id = channel_id.clone();
+                    let result: Result<ChatMessagesPage, _> = cx
+                        .background_spawn(async move {
+                            api.get_channel_messages_page(
+                                workspace_id,
+                                &request_channel_id,
+                                Some(&cursor),
+                            )
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let still_selected = view
+                            .selected_channel
+                            .as_ref()
+                            .is_some_and(|channel| channel.id == channel_id);
+                        if !still_selected {
+                            return;
+                        }
+
+                        view.history_loading = false;
+                        match result {
+                            Ok(mut page) => {
+                                page.messages.reverse();
+                                view.prepend_older_messages(page.messages);
+                                view.history_cursor = page.next_cursor;
+                                view.index_loaded_messages();
+                                view.load_message_media(cx);
+                                view.scroll_to_search_target(cx);
+                            }
+                            Err(err) => {
+                                view.scroll_target_id = None;
+                                if !view.handle_api_error(&err, cx) {
+                                    if err.is_not_found() {
+                                        view.channel_not_found(&channel_id, cx);
+                                    } else {
+                                        view.show_error_dialog(
+                                            "Failed to load older messages",
+                                            format!("{err}"),
+                                            cx,
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn prepend_older_messages(&mut self, older: Vec<ChatMessage>) {
+        let known_ids: HashSet<&str> = self.server_messages.iter().map(|m| m.id.as_str()).collect();
+        let mut merged: Vec<ChatMessage> = older
+            .into_iter()
+            .filter(|m| !known_ids.contains(m.id.as_str()))
+            .collect();
+
+        if merged.is_empty() {
+            return;
+        }
+
+        self.history_scroll_anchor = Some(self.scroll_handle.max_offset().height);
+        merged.append(&mut self.server_messages);
+        self.server_messages = merged;
+    }
+
+    fn merge_latest_page(&mut self, mut latest: ChatMessagesPage) -> bool {
+        latest
+            .messages
+            .retain(|message| !self.deleting_ids.contains(&message.id));
+        let first_overlap = latest.messages.first().and_then(|first| {
+            self.server_messages
+                .iter()
+                .position(|existing| existing.id == first.id)
+        });
+
+        match first_overlap {
+            Some(ix) => {
+                if self.server_messages[ix..] == latest.messages[..] {
+                    return false;
+                }
+                self.server_messages.truncate(ix);
+                self.server_messages.extend(latest.messages);
+            }
+            None => {
+                self.server_messages = latest.messages;
+                self.history_cursor = latest.next_cursor;
+            }
+        }
+        true
+    }
+
+    fn restore_history_scroll(&mut self, window: &mut Window) {
+        let Some(previous_max) = self.history_scroll_anchor.take() else {
+            return;
+        };
+
+        let scroll_handle = self.scroll_handle.clone();
+        window.on_next_frame(move |window, _cx| {
+            let delta = scroll_handle.max_offset().height - previous_max;
+            let offset = scroll_handle.offset();
+            scroll_handle.set_offset(point(offset.x, offset.y - delta));
+            window.refresh();
+        });
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll_handle.scroll_to_bottom();
+    }
+
+    pub fn mention_matches(&self) -> Vec<&ChannelMember> {
+        let Some(query) = self.mention_query.as_deref() else {
+            return Vec::new();
+        };
+
+        self.mention_members
+            .iter()
+            .filter(|member| query.is_empty() || member.matches(query))
+            .take(MENTION_SUGGESTION_LIMIT)
+            .collect()
+    }
+
+    fn fetch_mention_members(&mut self, channel_id: &str, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(_) => return,
+        };
+
+        let channel_id = channel_id.to_string();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let channel_id = channel_id.clone();
+                            async move { api.get_mention_candidates(workspace_id, &channel_id) }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let still_selected = view
+                            .selected_channel
+                            .as_ref()
+                            .is_some_and(|channel| channel.id == channel_id);
+                        if !still_selected {
+                            return;
+                        }
+
+                        match result {
+                            Ok(members) => view.mention_members = members,
+                            Err(ref err) => {
+                                view.handle_api_error(err, cx);
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn update_mention_query(&mut self, cx: &mut Context<Self>) {
+        let state = self.message_input.read(cx);
+        let value = state.unmask_value().to_string();
+        let cursor = state.cursor().min(value.len());
+
+        let mention = value[..cursor].rfind('@').and_then(|start| {
+            let preceded_by_space = value[..start]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace);
+            let query = &value[start + 1..cursor];
+            (preceded_by_space && !query.contains(char::is_whitespace))
+                .then(|| (start, query.to_string()))
+        });
+
+        let (start, query) = match mention {
+            Some((start, query)) => (Some(start), Some(query)),
+            None => (None, None),
+        };
+
+        if self.mention_query != query {
+            self.mention_selected = 0;
+        }
+        self.mention_start = start;
+        self.mention_query = query;
+        cx.notify();
+    }
+
+    pub fn mentions_open(&self) -> bool {
+        !self.mention_matches().is_empty()
+    }
+
+    pub fn move_mention_selection(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let count = self.mention_matches().len();
+        if count == 0 {
+            return;
+        }
+
+        self.mention_selected =
+            (self.mention_selected as isize + delta).rem_euclid(count as isize) as usize;
+        cx.notify();
+    }
+
+    pub fn dismiss_mentions(&mut self, cx: &mut Context<Self>) {
+        if self.mention_query.take().is_some() {
+            self.mention_start = None;
+            self.mention_selected = 0;
+            cx.notify();
+        }
+    }
+
+    pub fn accept_mention(&mut self, index: Option<usize>, cx: &mut Context<Self>) {
+        let index = index.unwrap_or(self.mention_selected);
+        let Some(member) = self.mention_matches().get(index).map(|m| (*m).clone()) else {
+            return;
+        };
+
+        let Some(start) = self.mention_start else {
+            return;
+        };
+
+        let state = self.message_input.read(cx);
+        let value = state.unmask_value().to_string();
+        let cursor = state.cursor().clamp(start, value.len());
+        let mention = format!("@{}", member.display_name());
+        let new_value = format!("{}{mention} {}", &value[..start], &value[cursor..]);
+
+        self.shift_draft_mentions(start..cursor, mention.len() + 1);
+        self.draft_mentions.push(DraftMention {
+            member,
+            range: start..start + mention.len(),
+        });
+        self.draft_value = new_value.clone();
+        self.mention_query = None;
+        self.mention_start = None;
+        self.mention_selected = 0;
+        self.fill_message_input_at(new_value, start + mention.len() + 1, cx);
+        cx.notify();
+    }
+
+    pub fn command_matches(&self) -> Vec<Rc<dyn SlashCommand>> {
+        match self.command_query.as_deref() {
+            Some(query) => self.commands.matching(query),
+            None => Vec::new(),
+        }
+    }
+
+    fn update_command_query(&mut self, cx: &mut Context<Self>) {
+        let value = self.message_input.read(cx).unmask_value();
+        let query = value
+            .strip_prefix('/')
+            .filter(|name| !name.contains(char::is_whitespace))
+            .map(str::to_string);
+
+        if self.command_query != query {
+            self.command_selected = 0;
+            self.command_query = query;
+            cx.notify();
+        }
+    }
+
+    pub fn commands_open(&self) -> bool {
+        match self.command_matches().as_slice() {
+            [] => false,
+            [command] => self
+                .command_query
+                .as_deref()
+                .is_none_or(|query| !command.name().eq_ignore_ascii_case(query)),
+            _ => true,
+        }
+    }
+
+    pub fn move_command_selection(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let count = self.command_matches().len();
+        if count == 0 {
+            return;
+        }
+
+        self.command_selected =
+            (self.command_selected as isize + delta).rem_euclid(count as isize) as usize;
+        cx.notify();
+    }
+
+    pub fn dismiss_commands(&mut self, cx: &mut Context<Self>) {
+        if self.command_query.take().is_some() {
+            self.command_selected = 0;
+            cx.notify();
+        }
+    }
+
+    pub fn accept_command(&mut self, index: Option<usize>, cx: &mut Context<Self>) {
+        let index = index.unwrap_or(self.command_selected);
+        let Some(command) = self.command_matches().get(index).cloned() else {
+            return;
+        };
+
+        self.command_query = None;
+        self.command_selected = 0;
+        self.fill_message_input(format!("/{} ", command.name()), cx);
+        cx.notify();
+    }
+
+    fn run_command(&mut self, content: &str, cx: &mut Context<Self>) -> bool {
+        let Some((name, args)) = parse_command(content) else {
+            return false;
+        };
+        let Some(command) = self.commands.get(name) else {
+            return false;
+        };
+
+        self.dismiss_commands(cx);
+        match command.run(args, self, cx) {
+            CommandOutcome::Send(content) => {
+                self.draft_mentions.clear();
+                self.dismiss_mentions(cx);
+                self.clear_message_input(cx);
+                self.post_message(content, Vec::new(), cx);
+            }
+            CommandOutcome::Handled => {
+                self.draft_mentions.clear();
+                self.clear_message_input(cx);
+            }
+            CommandOutcome::Invalid(message) => {
+                self.clickup_status = message.into();
+            }
+        }
+        cx.notify();
+        true
+    }
+
+    fn track_draft_edit(&mut self, cx: &mut Context<Self>) {
+        let value = self.message_input.read(cx).unmask_value().to_string();
+        if value == self.draft_value {
+            return;
+        }
+
+        let old = std::mem::replace(&mut self.draft_value, value);
+        let new = &self.draft_value;
+        let prefix: usize = old
+            .chars()
+            .zip(new.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        let suffix: usize = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        let inserted = new.len() - prefix - suffix;
+        self.shift_draft_mentions(prefix..old.len() - suffix, inserted);
+    }
+
+    fn shift_draft_mentions(&mut self, edit: Range<usize>, inserted: usize) {
+        self.draft_mentions.retain_mut(|mention| {
+            if mention.range.end <= edit.start {
+                true
+            } else if mention.range.start >= edit.end {
+                mention.range.start = mention.range.start - edit.len() + inserted;
+                mention.range.end = mention.range.end - edit.len() + inserted;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    fn apply_draft_mentions(&self, mut content: String) -> String {
+        let mut mentions: Vec<&DraftMention> = self.draft_mentions.iter().collect();
+        mentions.sort_by_key(|mention| std::cmp::Reverse(mention.range.start));
+        for mention in mentions {
+            let expected = format!("@{}", mention.member.display_name());
+            if content.get(mention.range.clone()) == Some(expected.as_str()) {
+                content.replace_range(mention.range.clone(), &mention.member.mention_markup());
+            }
+        }
+        content
+    }
+
+    pub fn send_message(&mut self, cx: &mut Context<Self>) {
+        let value = self.message_input.read(cx).unmask_value().to_string();
+
+        if value.trim().is_empty() {
+            self.clear_message_input(cx);
+            return;
+        }
+
+        if self.team_id.is_none() || self.selected_channel.is_none() {
+            return;
+        }
+
+        let content = self.apply_draft_mentions(value).trim().to_string();
+        if self.run_command(&content, cx) {
+            return;
+        }
+
+        self.draft_mentions.clear();
+        self.dismiss_mentions(cx);
+        self.clear_message_input(cx);
+        self.post_message(content, Vec::new(), cx);
+    }
+
+    pub fn attach_files(&mut self, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: true,
+            prompt: Some("Attach".into()),
+        });
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let Ok(Ok(Some(paths))) = paths.await else {
+                        return;
+                    };
+                    if paths.is_empty() {
+                        return;
+                    }
+
+                    let count = paths.len();
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.uploading_attachments += count;
+                        cx.notify();
+                    });
+
+                    let results = cx
+                        .background_spawn({
+                            let channel_id = channel_id.clone();
+                            async move {
+                                paths
+                                    .iter()
+                                    .map(|path| {
+                                        api.upload_attachment(workspace_id, &channel_id, path)
+                                            .map_err(|err| (path.clone(), err))
+                                    })
+                                    .collect::<Vec<_>>()
+                            }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.uploading_attachments =
+                            view.uploading_attachments.saturating_sub(count);
+
+                        let mut attachments = Vec::new();
+                        for result in results {
+                            match result {
+                                Ok(attachment) => attachments.push(attachment),
+                                Err((path, ref err)) => {
+                                    if !view.handle_api_error(err, cx) {
+                                        view.show_error_dialog(
+                                            "Failed to upload attachment",
+                                            format!("{}: {err}", path.display()),
+                                            cx,
+                                        );
+                                    }
+                                }
+                            }
+                        }
+
+                        let same_channel = view
+                            .selected_channel
+                            .as_ref()
+                            .is_some_and(|channel| channel.id == channel_id);
+                        if same_channel && !attachments.is_empty() {
+                            view.post_message(String::new(), attachments, cx);
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn attachment_image(&self, url: &str) -> Option<Arc<Image>> {
+        self.attachment_images.get(url).cloned()
+    }
+
+    fn load_message_media(&mut self, cx: &mut Context<Self>) {
+        self.load_attachment_images(cx);
+        self.load_task_previews(cx);
+    }
+
+    fn load_attachment_images(&mut self, cx: &mut Context<Self>) {
+        let wanted: Vec<MessageAttachment> = self
+            .server_messages
+            .iter()
+            .chain(self.thread_replies.iter())
+            .flat_map(|message| message.attachments.iter())
+            .filter(|attachment| attachment.is_image())
+            .filter(|attachment| {
+                !self
+                    .attachment_images_requested
+                    .contains(attachment.preview_url())
+            })
+            .cloned()
+            .collect();
+
+        if wanted.is_empty() {
+            return;
+        }
+
+        let Some(api) = self.api.clone() else {
+            return;
+        };
+
+        for attachment in wanted {
+            let url = attachment.preview_url().to_string();
+            if !self.attachment_images_requested.insert(url.clone()) {
+                continue;
+            }
+
+            let api = api.clone();
+            cx.spawn(
+                move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                    let mut cx = cx.clone();
+                    async move {
+                        let result = cx
+                            .background_spawn(
+                                async move { api.fetch_attachment_image(&attachment) },
+                            )
+                            .await;
+
+                        let _ = this.update(&mut cx, |view, cx| match result {
+                            Ok(image) => {
+                                view.attachment_images.insert(url, image);
+                                cx.notify();
+                            }
+                            Err(_) => {
+                                view.attachment_images_requested.remove(&url);
+                            }
+                        });
+                    }
+                },
+            )
+            .detach();
+        }
+    }
+
+    pub fn task_preview(&self, task_ref: &TaskRef) -> Option<&ClickUpTask> {
+        self.task_previews.get(task_ref)
+    }
+
+    pub fn task_refs(&self, message: &ChatMessage) -> Vec<TaskRef> {
+        let Some(content) = message.content() else {
+            return Vec::new();
+        };
+
+        match self
+            .team_id
+            .and_then(|workspace_id| self.settings.task_id_prefixes.get(&workspace_id))
+        {
+            Some(prefixes) => find_task_refs(content, prefixes),
+            None => find_task_refs(content, &HashSet::new()),
+        }
+    }
+
+    fn learn_task_prefix(&mut self, task: &ClickUpTask, cx: &mut Context<Self>) {
+        let (Some(prefix), Some(workspace_id)) = (task.custom_id_prefix(), self.team_id) else {
+            return;
+        };
+
+        let inserted = self
+            .settings
+            .task_id_prefixes
+            .entry(workspace_id)
+            .or_default()
+            .insert(prefix.to_string());
+        if inserted {
+            self.save_settings(cx);
+        }
+    }
+
+    fn load_task_previews(&mut self, cx: &mut Context<Self>) {
+        let wanted: Vec<TaskRef> = self
+            .server_messages
+            .iter()
+            .chain(self.thread_replies.iter())
+            .chain(self.thread_parent.iter())
+            .flat_map(|message| self.task_refs(message))
+            .collect();
+
+        for task_ref in wanted {
+            if self.task_previews_requested.insert(task_ref.clone()) {
+                self.task_preview_queue.push_back(task_ref);
+            }
+        }
+        self.pump_task_previews(cx);
+    }
+
+    fn pump_task_previews(&mut self, cx: &mut Context<Self>) {
+        let (Some(api), Some(workspace_id)) = (self.api.clone(), self.team_id) else {
+            return;
+        };
+
+        while self.task_previews_in_flight < TASK_PREVIEW_CONCURRENCY {
+            let Some(task_ref) = self.task_preview_queue.pop_front() else {
+                return;
+            };
+
+            self.task_previews_in_flight += 1;
+            let api = api.clone();
+            cx.spawn(
+                move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                    let mut cx = cx.clone();
+                    async move {
+                        let request_ref = task_ref.clone();
+                        let result = cx
+                            .background_spawn(
+                                async move { api.get_task(workspace_id, &request_ref) },
+                            )
+                            .await;
+
+                        let _ = this.update(&mut cx, |view, cx| {
+                            view.task_previews_in_flight -= 1;
+                            if view.team_id == Some(workspace_id) {
+                                match result {
+                                    Ok(task) => {
+                                        view.learn_task_prefix(&task, cx);
+                                        view.task_previews.insert(task_ref, task);
+                                        cx.notify();
+                                    }
+                                    Err(err) => {
+                                        view.handle_rate_limit(&err);
+                                        if !err.is_not_found() {
+                                            view.task_previews_requested.remove(&task_ref);
+                                        }
+                                    }
+                                }
+                            }
+                            view.pump_task_previews(cx);
+                        });
+                    }
+                },
+            )
+            .detach();
+        }
+    }
+
+    pub fn save_attachment(&mut self, attachment: MessageAttachment, cx: &mut Context<Self>) {
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        let directory = dirs::download_dir()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| std::path::PathBuf::from("."));
+        let path = cx.prompt_for_new_path(&directory, Some(&attachment.display_name()));
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let Ok(Ok(Some(path))) = path.await else {
+                        return;
+                    };
+
+                    let result = cx
+                        .background_spawn(async move {
+                            let bytes = api.download_attachment(&attachment.url)?;
+                            std::fs::write(&path, bytes)?;
+                            Ok::<_, AppError>(())
+                        })
+                        .await;
+
+                    if let Err(err) = result {
+                        let _ = this.update(&mut cx, |view, cx| {
+                            view.show_error_dialog(
+                                "Failed to save attachment",
+                                format!("{err}"),
+                                cx,
+                            );
+                        });
+                    }
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn export_channel(&mut self, format: ExportFormat, cx: &mut Context<Self>) {
+        if self.exporting {
+            return;
+        }
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+        let Some(channel) = self.selected_channel.clone() else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        let directory = dirs::document_dir()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| std::path::PathBuf::from("."));
+        let path = cx.prompt_for_new_path(&directory, Some(&export_file_name(&channel, format)));
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let Ok(Ok(Some(path))) = path.await else {
+                        return;
+                    };
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.exporting = true;
+                        view.clickup_status =
+                            format!("Exporting {}…", channel.display_name()).into();
+                        cx.notify();
+                    });
+
+                    let result = cx
+                        .background_spawn({
+                            let path = path.clone();
+                            async move {
+                                let export = ChannelExport::fetch(&api, workspace_id, channel)?;
+                                export.write_to(format, &path)?;
+                                Ok::<_, AppError>(export.messages.len())
+                            }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.exporting = false;
+                        match result {
+                            Ok(count) => {
+                                view.clickup_status =
+                                    format!("Exported {count} messages to {}", path.display())
+                                        .into();
+                            }
+                            Err(err) => {
+                                if !view.handle_api_error(&err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to export chat",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn post_message(
+        &mut self,
+        content: String,
+        attachments: Vec<MessageAttachment>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(ref channel) = self.selected_channel else {
+            return;
+        };
+
+        let entry = OutboxEntry::new(workspace_id, channel.id.clone(), content, attachments);
+        let nonce = entry.nonce.clone();
+        self.outbox.push(entry);
+        self.send_outbox_entry(&nonce, cx);
+        self.scroll_to_bottom();
+    }
+
+    fn sync_outbox_messages(&mut self) {
+        let (user_id, username) = self
+            .user
+            .as_ref()
+            .map(|u| (u.id.to_string(), u.username.clone()))
+            .unwrap_or_else(|| ("0".to_string(), "You".to_string()));
+        let selected = self
+            .team_id
+            .zip(self.selected_channel.as_ref().map(|c| c.id.as_str()));
+
+        self.pending_messages = self
+            .outbox
+            .iter()
+            .filter(|entry| selected == Some((entry.workspace_id, entry.channel_id.as_str())))
+            .map(|entry| entry.to_message(user_id.clone(), username.clone()))
+            .collect();
+    }
+
+    fn persist_outbox(&mut self, cx: &mut Context<Self>) {
+        self.sync_outbox_messages();
+        let cache = self.cache.clone();
+        let outbox = self.outbox.clone();
+        cx.background_spawn(async move {
+            let _ = cache.save_outbox(&outbox);
+        })
+        .detach();
+        cx.notify();
+    }
+
+    fn flush_outbox(&mut self, cx: &mut Context<Self>) {
+        let now = now_millis();
+        let nonces: Vec<String> = self
+            .outbox
+            .iter()
+            .filter(|entry| entry.should_auto_retry(now))
+            .map(|entry| entry.nonce.clone())
+            .collect();
+        for nonce in nonces {
+            self.send_outbox_entry(&nonce, cx);
+        }
+    }
+
+    pub fn retry_outbox_message(&mut self, message_id: &str, cx: &mut Context<Self>) {
+        if let Some(nonce) = nonce_from_message_id(message_id) {
+            let nonce = nonce.to_string();
+            self.send_outbox_entry(&nonce, cx);
+        }
+    }
+
+    pub fn discard_outbox_message(&mut self, message_id: &str, cx: &mut Context<Self>) {
+        let Some(nonce) = nonce_from_message_id(message_id) else {
+            return;
+        };
+
+        self.outbox
+            .retain(|entry| entry.nonce != nonce || entry.is_sending());
+        self.persist_outbox(cx);
+    }
+
+    fn send_outbox_entry(&mut self, nonce: &str, cx: &mut Context<Self>) {
+        let Some(ix) = self
+            .outbox
+            .iter()
+            .position(|entry| entry.nonce == nonce && (entry.attempts == 0 || !entry.is_sending()))
+        else {
+            return;
+        };
+
+        self.outbox[ix].begin_attempt();
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.outbox[ix].fail(format!("{err}"), true);
+                self.persist_outbox(cx);
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        let entry = self.outbox[ix].clone();
+        self.persist_outbox(cx);
+
+        let (user_id, username) = self
+            .user
+            .as_ref()
+            .map(|u| (u.id.to_string(), u.username.clone()))
+            .unwrap_or_else(|| ("0".to_string(), "You".to_string()));
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let request = entry.clone();
+                    let result = cx
+                        .background_spawn(async move {
+                            api.send_message(
+                                request.workspace_id,
+                                &request.channel_id,
+                                &request.content,
+                                &request.attachments,
+                            )
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let is_selected = view.team_id == Some(entry.workspace_id)
+                            && view
+                                .selected_channel
+                                .as_ref()
+                                .is_some_and(|channel| channel.id == entry.channel_id);
+
+                        match result {
+                            Ok(mut confirmed) => {
+                                if confirmed.creator.is_none() {
+                                    confirmed.creator = Some(crate::api::MessageCreator {
+                                        id: user_id.clone(),
+                                        username: Some(username.clone()),
+                                        email: None,
+                                        profile_picture: None,
+                                    });
+                                }
+
+                                view.outbox.retain(|e| e.nonce != entry.nonce);
+                                if is_selected {
+                                    if !view.server_messages.iter().any(|m| m.id == confirmed.id) {
+                                        view.server_messages.push(confirmed);
+                                    }
+                                    view.load_message_media(cx);
+                                    view.scroll_to_bottom();
+                                    view.cache_messages(cx);
+                                }
+                            }
+                            Err(ref err) if err.is_not_found() => {
+                                view.outbox.retain(|e| e.nonce != entry.nonce);
+                                view.channel_not_found(&entry.channel_id, cx);
+                            }
+                            Err(ref err) => {
+                                let retryable = err.is_transient()
+                                    || matches!(err, AppError::RateLimited { .. });
+                                view.handle_api_error(err, cx);
+                                if let Some(failed) =
+                                    view.outbox.iter_mut().find(|e| e.nonce == entry.nonce)
+                                {
+                                    failed.fail(format!("{err}"), retryable);
+                                }
+                            }
+                        }
+                        view.persist_outbox(cx);
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn start_editing(&mut self, message_id: &str, cx: &mut Context<Self>) {
+        let Some(content) = self
+            .server_messages
+            .iter()
+            .find(|m| m.id == message_id)
+            .map(|m| m.content().unwrap_or_default().to_string())
+        else {
+            return;
+        };
+
+        self.editing_message_id = Some(message_id.to_string());
+        self.fill_message_input(content, cx);
+        self.set_message_input_placeholder("Edit message...", cx);
+        cx.notify();
+    }
+
+    pub fn cancel_editing(&mut self, cx: &mut Context<Self>) {
+        if self.editing_message_id.take().is_none() {
+            return;
+        }
+
+        self.clear_message_input(cx);
+        self.restore_message_input_placeholder(cx);
+        cx.notify();
+    }
+
+    pub fn save_edit(&mut self, cx: &mut Context<Self>) {
+        let Some(message_id) = self.editing_message_id.clone() else {
+            return;
+        };
+
+        let content = self.message_input.read(cx).unmask_value().to_string();
+        let content = content.trim().to_string();
+
+        let unchanged = self
+            .server_messages
+            .iter()
+            .find(|m| m.id == message_id)
+            .is_none_or(|m| m.content().map(str::trim) == Some(content.as_str()));
+        if content.is_empty() || unchanged {
+            self.cancel_editing(cx);
+            return;
+        }
+
         let Some(workspace_id) = self.team_id else {
             return;
         };
 
-        let api = match ClickUpApi::from_env() {
+        let api = match self.api() {
             Ok(api) => api,
-            Err(_) => return,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
         };
 
-        let channel_id = channel_id.to_string();
+        self.editing_message_id = None;
+        self.clear_message_input(cx);
+        self.restore_message_input_placeholder(cx);
+        cx.notify();
 
         cx.spawn(
             move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
                 let mut cx = cx.clone();
                 async move {
-                    let result: Result<Vec<ChatMessage>, _> = cx
+                    let (request_id, request_content) = (message_id.clone(), content.clone());
+                    let result = cx
                         .background_spawn(async move {
-                            api.get_channel_messages(workspace_id, &channel_id)
+                            api.edit_message(workspace_id, &request_id, &request_content)
                         })
                         .await;
 
                     let _ = this.update(&mut cx, |view, cx| {
-                        if let Ok(mut new_messages) = result {
-                            new_messages.reverse();
-
-                            let confirmed_ids: HashSet<String> =
-                                new_messages.iter().map(|m| m.id.clone()).collect();
-                            view.pending_messages
-                                .retain(|p| !confirmed_ids.contains(&p.id));
-                            view.pending_ids.retain(|id| !confirmed_ids.contains(id));
-
-                            view.server_messages = new_messages;
-                            cx.notify();
+                        match result {
+                            Ok(mut edited) => {
+                                if let Some(existing) =
+                                    view.server_messages.iter_mut().find(|m| m.id == edited.id)
+                                {
+                                    if edited.creator.is_none() {
+                                        edited.creator = existing.creator.clone();
+                                    }
+                                    *existing = edited;
+                                }
+                            }
+                            Err(ref err) => {
+                                view.restore_failed_edit(message_id, content, cx);
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to edit message",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
+                            }
                         }
+                        cx.notify();
                     });
                 }
             },
@@ -336,21 +3987,28 @@ impl ClickLiteApp {
         .detach();
     }
 
-    pub fn fetch_messages(&mut self, channel_id: &str, cx: &mut Context<Self>) {
-        if self.messages_loading {
+    fn restore_failed_edit(&mut self, message_id: String, content: String, cx: &mut Context<Self>) {
+        let draft_empty = self.message_input.read(cx).value().trim().is_empty();
+        let still_loaded = self.server_messages.iter().any(|m| m.id == message_id);
+        if self.editing_message_id.is_some() || !draft_empty || !still_loaded {
             return;
         }
 
+        self.editing_message_id = Some(message_id);
+        self.fill_message_input(content, cx);
+        self.set_message_input_placeholder("Edit message...", cx);
+    }
+
+    pub fn toggle_reaction(&mut self, message_id: &str, reaction: &str, cx: &mut Context<Self>) {
         let Some(workspace_id) = self.team_id else {
-            self.show_error_dialog(
-                "Configuration error",
-                "Missing CLICKUP_WORKSPACE_ID (or CLICKUP_TEAM_ID) in .env",
-                cx,
-            );
             return;
         };
 
-        let api = match ClickUpApi::from_env() {
+        let Some(user_id) = self.user.as_ref().map(|u| u.id.to_string()) else {
+            return;
+        };
+
+        let api = match self.api() {
             Ok(api) => api,
             Err(err) => {
                 self.show_error_dialog("Configuration error", format!("{err}"), cx);
@@ -358,35 +4016,208 @@ impl ClickLiteApp {
             }
         };
 
-        self.messages_loading = true;
+        let Some(message) = self.server_messages.iter_mut().find(|m| m.id == message_id) else {
+            return;
+        };
+
+        let removing = message.has_reaction(reaction, &user_id);
+        if removing {
+            message.remove_reaction(reaction, &user_id);
+        } else {
+            message.add_reaction(reaction, &user_id);
+        }
         cx.notify();
 
-        let channel_id = channel_id.to_string();
+        let message_id = message_id.to_string();
+        let reaction = canonical_reaction(reaction).to_string();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let message_id = message_id.clone();
+                            let reaction = reaction.clone();
+                            async move {
+                                if removing {
+                                    api.remove_reaction(workspace_id, &message_id, &reaction)
+                                } else {
+                                    api.add_reaction(workspace_id, &message_id, &reaction)
+                                }
+                            }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        let Err(ref err) = result else {
+                            return;
+                        };
+
+                        if let Some(message) =
+                            view.server_messages.iter_mut().find(|m| m.id == message_id)
+                        {
+                            if removing {
+                                message.add_reaction(&reaction, &user_id);
+                            } else {
+                                message.remove_reaction(&reaction, &user_id);
+                            }
+                        }
+
+                        if !view.handle_api_error(err, cx) {
+                            view.show_error_dialog(
+                                "Failed to update reaction",
+                                format!("{err}"),
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
+
+    pub fn open_thread(&mut self, message_id: &str, cx: &mut Context<Self>) {
+        let Some(parent) = self
+            .server_messages
+            .iter()
+            .find(|m| m.id == message_id)
+            .cloned()
+        else {
+            return;
+        };
+
+        self.channel_details_open = false;
+        self.close_search(cx);
+        self.thread_generation += 1;
+        self.thread_parent = Some(parent);
+        self.thread_replies.clear();
+        self.thread_loading = true;
+        self.thread_sending = false;
+        self.fetch_thread_replies(cx);
+        self.start_thread_refresh(cx);
+
+        let input = self.thread_input.clone();
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            input.update(cx, |state, cx| {
+                state.set_value("", window, cx);
+                state.focus(window, cx);
+            });
+        });
+        cx.notify();
+    }
+
+    pub fn close_thread(&mut self, cx: &mut Context<Self>) {
+        if self.thread_parent.take().is_none() {
+            return;
+        }
+
+        self.thread_generation += 1;
+        self.thread_replies.clear();
+        self.thread_loading = false;
+        self.thread_sending = false;
+        cx.notify();
+    }
+
+    fn start_thread_refresh(&mut self, cx: &mut Context<Self>) {
+        let generation = self.thread_generation;
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    loop {
+                        cx.background_executor()
+                            .timer(std::time::Duration::from_secs(5))
+                            .await;
+
+                        let should_continue = this
+                            .update(&mut cx, |view, cx| {
+                                if view.thread_generation != generation {
+                                    return false;
+                                }
+                                if view
+                                    .refresh_paused_until
+                                    .is_none_or(|until| Instant::now() >= until)
+                                {
+                                    view.fetch_thread_replies(cx);
+                                }
+                                true
+                            })
+                            .unwrap_or(false);
+
+                        if !should_continue {
+                            break;
+                        }
+                    }
+                }
+            },
+        )
+        .detach();
+    }
+
+    fn fetch_thread_replies(&mut self, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let Some(message_id) = self.thread_parent.as_ref().map(|m| m.id.clone()) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(_) => return,
+        };
+
+        let generation = self.thread_generation;
 
         cx.spawn(
             move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
                 let mut cx = cx.clone();
                 async move {
-                    let result: Result<Vec<ChatMessage>, _> = cx
+                    let result = cx
                         .background_spawn(async move {
-                            api.get_channel_messages(workspace_id, &channel_id)
+                            api.get_message_replies(workspace_id, &channel_id, &message_id)
                         })
                         .await;
 
                     let _ = this.update(&mut cx, |view, cx| {
-                        view.messages_loading = false;
+                        if view.thread_generation != generation {
+                            return;
+                        }
+
+                        let was_loading = view.thread_loading;
+                        view.thread_loading = false;
+
                         match result {
-                            Ok(mut messages) => {
-                                messages.reverse();
-                                view.server_messages = messages;
-                                view.scroll_to_bottom();
+                            Ok(replies) => {
+                                let grew = replies.len() > view.thread_replies.len();
+                                if let Some(parent) = view.thread_parent.as_mut() {
+                                    parent.replies_count = replies.len() as u64;
+                                }
+                                view.thread_replies = replies;
+                                view.load_message_media(cx);
+                                if was_loading || grew {
+                                    view.thread_scroll_handle.scroll_to_bottom();
+                                }
                             }
-                            Err(err) => {
-                                view.show_error_dialog(
-                                    "Failed to load messages",
-                                    format!("{err}"),
-                                    cx,
-                                );
+                            Err(ref err) => {
+                                if !view.handle_api_error(err, cx) && was_loading {
+                                    if err.is_not_found() {
+                                        view.close_thread(cx);
+                                    }
+                                    view.show_error_dialog(
+                                        "Failed to load thread",
+                                        format!("{err}"),
+                                        cx,
+                                    );
+                                }
                             }
                         }
                         cx.notify();
@@ -397,16 +4228,11 @@ impl ClickLiteApp {
         .detach();
     }
 
-    pub fn scroll_to_bottom(&mut self) {
-        self.scroll_handle.scroll_to_bottom();
-    }
-
-    pub fn send_message(&mut self, cx: &mut Context<Self>) {
-        let content = self.message_input.read(cx).unmask_value().to_string();
+    pub fn send_reply(&mut self, cx: &mut Context<Self>) {
+        let content = self.thread_input.read(cx).unmask_value().to_string();
         let content = content.trim().to_string();
 
-        if content.is_empty() {
-            self.clear_message_input(cx);
+        if content.is_empty() || self.thread_sending {
             return;
         }
 
@@ -414,13 +4240,11 @@ impl ClickLiteApp {
             return;
         };
 
-        let Some(ref channel) = self.selected_channel else {
+        let Some(message_id) = self.thread_parent.as_ref().map(|m| m.id.clone()) else {
             return;
         };
 
-        let channel_id = channel.id.clone();
-
-        let api = match ClickUpApi::from_env() {
+        let api = match self.api() {
             Ok(api) => api,
             Err(err) => {
                 self.show_error_dialog("Configuration error", format!("{err}"), cx);
@@ -428,68 +4252,166 @@ impl ClickLiteApp {
             }
         };
 
-        let (user_id, username) = self
-            .user
-            .as_ref()
-            .map(|u| (u.id.to_string(), u.username.clone()))
-            .unwrap_or_else(|| ("0".to_string(), "You".to_string()));
-
-        let user_id_for_enrichment = user_id.clone();
-        let username_for_enrichment = username.clone();
-
-        let temp_id = format!(
-            "pending_{}",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_millis())
-                .unwrap_or(0)
-        );
-
-        let pending_message =
-            ChatMessage::new_pending(temp_id.clone(), content.clone(), user_id, username);
+        let creator = self.user.as_ref().map(|u| crate::api::MessageCreator {
+            id: u.id.to_string(),
+            username: Some(u.username.clone()),
+            email: None,
+            profile_picture: None,
+        });
 
-        self.pending_messages.push(pending_message);
-        self.pending_ids.insert(temp_id.clone());
-        self.scroll_to_bottom();
-        self.clear_message_input(cx);
+        self.thread_sending = true;
+        let input = self.thread_input.clone();
+        let _ = cx.update_window(self.window_handle, move |_, window, cx| {
+            input.update(cx, |state, cx| state.set_value("", window, cx));
+        });
         cx.notify();
 
+        let generation = self.thread_generation;
+        let failed_content = content.clone();
+
         cx.spawn(
             move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
                 let mut cx = cx.clone();
                 async move {
                     let result = cx
-                        .background_spawn(async move {
-                            api.send_message(workspace_id, &channel_id, &content)
+                        .background_spawn({
+                            let message_id = message_id.clone();
+                            async move { api.send_reply(workspace_id, &message_id, &content) }
                         })
                         .await;
 
                     let _ = this.update(&mut cx, |view, cx| {
+                        if view.thread_generation != generation {
+                            return;
+                        }
+                        view.thread_sending = false;
+
                         match result {
-                            Ok(mut confirmed) => {
-                                if confirmed.creator.is_none() {
-                                    confirmed.creator = Some(crate::api::MessageCreator {
-                                        id: user_id_for_enrichment.clone(),
-                                        username: Some(username_for_enrichment.clone()),
-                                        email: None,
-                                        profile_picture: None,
+                            Ok(mut reply) => {
+                                if reply.creator.is_none() {
+                                    reply.creator = creator;
+                                }
+                                if !view.thread_replies.iter().any(|m| m.id == reply.id) {
+                                    view.thread_replies.push(reply);
+                                }
+                                let count = view.thread_replies.len() as u64;
+                                if let Some(parent) = view.thread_parent.as_mut() {
+                                    parent.replies_count = count;
+                                }
+                                if let Some(parent) =
+                                    view.server_messages.iter_mut().find(|m| m.id == message_id)
+                                {
+                                    parent.replies_count = count;
+                                }
+                                view.thread_scroll_handle.scroll_to_bottom();
+                            }
+                            Err(ref err) => {
+                                let input = view.thread_input.clone();
+                                let _ =
+                                    cx.update_window(view.window_handle, move |_, window, cx| {
+                                        input.update(cx, |state, cx| {
+                                            state.set_value(failed_content, window, cx)
+                                        });
                                     });
+                                if !view.handle_api_error(err, cx) {
+                                    view.show_error_dialog(
+                                        "Failed to send reply",
+                                        format!("{err}"),
+                                        cx,
+                                    );
                                 }
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            },
+        )
+        .detach();
+    }
 
-                                view.pending_ids.remove(&temp_id);
-                                view.pending_messages.retain(|m| m.id != temp_id);
+    pub fn confirm_delete_message(&mut self, message_id: String, cx: &mut Context<Self>) {
+        self.confirm_action(
+            "Delete message",
+            "This message will be deleted for everyone in the chat.",
+            "Delete",
+            move |this, cx| this.delete_message(message_id.clone(), cx),
+            cx,
+        );
+    }
 
-                                if !view.server_messages.iter().any(|m| m.id == confirmed.id) {
-                                    view.server_messages.push(confirmed);
-                                }
+    fn delete_message(&mut self, message_id: String, cx: &mut Context<Self>) {
+        let Some(workspace_id) = self.team_id else {
+            return;
+        };
+
+        let Some(channel_id) = self.selected_channel.as_ref().map(|c| c.id.clone()) else {
+            return;
+        };
+
+        let Some(index) = self.server_messages.iter().position(|m| m.id == message_id) else {
+            return;
+        };
+
+        let api = match self.api() {
+            Ok(api) => api,
+            Err(err) => {
+                self.show_error_dialog("Configuration error", format!("{err}"), cx);
+                return;
+            }
+        };
+
+        if self.editing_message_id.as_deref() == Some(message_id.as_str()) {
+            self.cancel_editing(cx);
+        }
+
+        if self
+            .thread_parent
+            .as_ref()
+            .is_some_and(|parent| parent.id == message_id)
+        {
+            self.close_thread(cx);
+        }
+
+        let removed = self.server_messages.remove(index);
+        self.deleting_ids.insert(message_id.clone());
+        self.search_index.remove_message(&message_id);
+        self.refresh_search_results();
+        cx.notify();
+
+        cx.spawn(
+            move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                let mut cx = cx.clone();
+                async move {
+                    let result = cx
+                        .background_spawn({
+                            let message_id = message_id.clone();
+                            async move { api.delete_message(workspace_id, &message_id) }
+                        })
+                        .await;
+
+                    let _ = this.update(&mut cx, |view, cx| {
+                        view.deleting_ids.remove(&message_id);
 
-                                view.scroll_to_bottom();
+                        if let Err(ref err) = result {
+                            if err.is_not_found() {
+                                return;
                             }
-                            Err(ref err) => {
-                                view.pending_ids.remove(&temp_id);
-                                view.pending_messages.retain(|m| m.id != temp_id);
+
+                            let same_channel = view
+                                .selected_channel
+                                .as_ref()
+                                .is_some_and(|channel| channel.id == channel_id);
+                            if same_channel
+                                && !view.server_messages.iter().any(|m| m.id == removed.id)
+                            {
+                                let index = index.min(view.server_messages.len());
+                                view.server_messages.insert(index, removed);
+                            }
+
+                            if !view.handle_api_error(err, cx) {
                                 view.show_error_dialog(
-                                    "Failed to send message",
+                                    "Failed to delete message",
                                     format!("{err}"),
                                     cx,
                                 );
@@ -504,8 +4426,18 @@ impl ClickLiteApp {
     }
 }
 
+fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
 impl gpui::Render for ClickLiteApp {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.restore_history_scroll(window);
+        self.mark_selected_channel_read(window, cx);
+
         div()
             .id("root")
             .size_full()
@@ -514,6 +4446,12 @@ impl gpui::Render for ClickLiteApp {
             .bg(cx.theme().background)
             .text_color(cx.theme().foreground)
             .track_focus(&self.focus_handle)
+            .on_action(cx.listener(|this, _: &SearchInChannel, _window, cx| {
+                this.open_search(SearchScope::Channel, cx);
+            }))
+            .on_action(cx.listener(|this, _: &SearchWorkspace, _window, cx| {
+                this.open_search(SearchScope::Workspace, cx);
+            }))
             .child(render_sidebar(self, cx))
             .child(
                 div()
@@ -525,5 +4463,14 @@ impl gpui::Render for ClickLiteApp {
                     .child(render_header(self, cx))
                     .child(render_chat_area(self, window, cx)),
             )
+            .when(self.thread_parent.is_some(), |this| {
+                this.child(render_thread_panel(self, window, cx))
+            })
+            .when(self.channel_details_open, |this| {
+                this.child(render_channel_details(self, cx))
+            })
+            .when(self.search_open, |this| {
+                this.child(render_search_panel(self, cx))
+            })
     }
 }
@@ -0,0 +1,153 @@
+use crate::api::{ChannelMember, ChatMessage, ClickUpChatChannel, ClickUpUser};
+use crate::error::AppError;
+use crate::outbox::OutboxEntry;
+use crate::settings::data_dir;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const CACHE_DIR: &str = "cache";
+const USER_FILE: &str = "user.json";
+const OUTBOX_FILE: &str = "outbox.json";
+const CHANNELS_FILE: &str = "channels.json";
+const MEMBERS_FILE: &str = "members.json";
+const MESSAGES_FILE: &str = "messages.json";
+const CACHED_MESSAGES_LIMIT: usize = 200;
+
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Clone, Debug)]
+pub struct LocalCache {
+    root: Option<PathBuf>,
+}
+
+impl Default for LocalCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalCache {
+    pub fn new() -> Self {
+        Self {
+            root: data_dir().map(|dir| dir.join(CACHE_DIR)),
+        }
+    }
+
+    pub fn load_user(&self) -> Option<ClickUpUser> {
+        self.read(PathBuf::from(USER_FILE))
+    }
+
+    pub fn save_user(&self, user: &ClickUpUser) -> Result<(), AppError> {
+        self.write(PathBuf::from(USER_FILE), user)
+    }
+
+    pub fn load_outbox(&self) -> Vec<OutboxEntry> {
+        self.read(PathBuf::from(OUTBOX_FILE)).unwrap_or_default()
+    }
+
+    pub fn save_outbox(&self, outbox: &[OutboxEntry]) -> Result<(), AppError> {
+        self.write(PathBuf::from(OUTBOX_FILE), outbox)
+    }
+
+    pub fn load_channels(&self, workspace_id: u64) -> Option<Vec<ClickUpChatChannel>> {
+        self.read(workspace_path(workspace_id).join(CHANNELS_FILE))
+    }
+
+    pub fn save_channels(
+        &self,
+        workspace_id: u64,
+        channels: &[ClickUpChatChannel],
+    ) -> Result<(), AppError> {
+        self.write(workspace_path(workspace_id).join(CHANNELS_FILE), channels)
+    }
+
+    pub fn load_members(&self, workspace_id: u64, channel_id: &str) -> Option<Vec<ChannelMember>> {
+        self.read(channel_path(workspace_id, channel_id).join(MEMBERS_FILE))
+    }
+
+    pub fn save_members(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        members: &[ChannelMember],
+    ) -> Result<(), AppError> {
+        self.write(
+            channel_path(workspace_id, channel_id).join(MEMBERS_FILE),
+            members,
+        )
+    }
+
+    pub fn load_messages(&self, workspace_id: u64, channel_id: &str) -> Option<Vec<ChatMessage>> {
+        self.read(channel_path(workspace_id, channel_id).join(MESSAGES_FILE))
+    }
+
+    pub fn save_messages(
+        &self,
+        workspace_id: u64,
+        channel_id: &str,
+        messages: &[ChatMessage],
+    ) -> Result<(), AppError> {
+        let confirmed: Vec<&ChatMessage> = messages.iter().filter(|m| !m.pending).collect();
+        let start = confirmed.len().saturating_sub(CACHED_MESSAGES_LIMIT);
+        self.write(
+            channel_path(workspace_id, channel_id).join(MESSAGES_FILE),
+            &confirmed[start..],
+        )
+    }
+
+    pub fn clear(&self) -> Result<(), AppError> {
+        match self.root.as_ref() {
+            Some(root) if root.exists() => Ok(fs::remove_dir_all(root)?),
+            _ => Ok(()),
+        }
+    }
+
+    fn read<T: DeserializeOwned>(&self, relative: PathBuf) -> Option<T> {
+        let path = self.root.as_ref()?.join(relative);
+        let text = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    fn write<T: Serialize + ?Sized>(&self, relative: PathBuf, value: &T) -> Result<(), AppError> {
+        let root = self
+            .root
+            .as_ref()
+            .ok_or_else(|| AppError::Config("no data directory available".to_string()))?;
+        let path = root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let text = serde_json::to_string(value)
+            .map_err(|err| AppError::Parse(format!("Failed to serialize cache: {err}")))?;
+        let temp_path = path.with_extension(format!(
+            "json.{}.{}.tmp",
+            std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&temp_path, text)?;
+        fs::rename(temp_path, path)?;
+        Ok(())
+    }
+}
+
+fn workspace_path(workspace_id: u64) -> PathBuf {
+    PathBuf::from("workspaces").join(workspace_id.to_string())
+}
+
+fn channel_path(workspace_id: u64, channel_id: &str) -> PathBuf {
+    let safe_id: String = channel_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    workspace_path(workspace_id).join("channels").join(safe_id)
+}
@@ -0,0 +1,302 @@
+use crate::app::ClickLiteApp;
+use crate::export::ExportFormat;
+use crate::search::SearchScope;
+use gpui::Context;
+use std::rc::Rc;
+
+const SHRUG: &str = r"¯\\\_(ツ)\_/¯";
+
+pub enum CommandOutcome {
+    Send(String),
+    Handled,
+    Invalid(String),
+}
+
+pub trait SlashCommand {
+    fn name(&self) -> &'static str;
+
+    fn args(&self) -> &'static str {
+        ""
+    }
+
+    fn description(&self) -> &'static str;
+
+    fn run(
+        &self,
+        args: &str,
+        app: &mut ClickLiteApp,
+        cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome;
+
+    fn usage(&self) -> String {
+        match self.args() {
+            "" => format!("/{}", self.name()),
+            args => format!("/{} {args}", self.name()),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: Vec<Rc<dyn SlashCommand>>,
+}
+
+impl CommandRegistry {
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::default();
+        registry.register(Task);
+        registry.register(Code);
+        registry.register(Shrug);
+        registry.register(Me);
+        registry.register(Search);
+        registry.register(Export);
+        registry.register(Mute);
+        registry
+    }
+
+    pub fn register(&mut self, command: impl SlashCommand + 'static) {
+        self.commands
+            .retain(|existing| existing.name() != command.name());
+        self.commands.push(Rc::new(command));
+    }
+
+    pub fn get(&self, name: &str) -> Option<Rc<dyn SlashCommand>> {
+        self.commands
+            .iter()
+            .find(|command| command.name().eq_ignore_ascii_case(name))
+            .cloned()
+    }
+
+    pub fn matching(&self, prefix: &str) -> Vec<Rc<dyn SlashCommand>> {
+        let prefix = prefix.to_lowercase();
+        self.commands
+            .iter()
+            .filter(|command| command.name().starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn parse_command(input: &str) -> Option<(&str, &str)> {
+    let rest = input.strip_prefix('/')?;
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    let name = &rest[..end];
+    if name.is_empty() {
+        return None;
+    }
+
+    Some((name, rest[end..].trim_start_matches([' ', '\t'])))
+}
+
+struct Task;
+
+impl SlashCommand for Task {
+    fn name(&self) -> &'static str {
+        "task"
+    }
+
+    fn args(&self) -> &'static str {
+        "<name>"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a ClickUp task"
+    }
+
+    fn run(
+        &self,
+        args: &str,
+        app: &mut ClickLiteApp,
+        cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome {
+        let name = args.trim();
+        if name.is_empty() {
+            return CommandOutcome::Invalid(format!("Usage: {}", self.usage()));
+        }
+
+        app.open_new_task_dialog(name.to_string(), cx);
+        CommandOutcome::Handled
+    }
+}
+
+struct Code;
+
+impl SlashCommand for Code {
+    fn name(&self) -> &'static str {
+        "code"
+    }
+
+    fn args(&self) -> &'static str {
+        "<lang> <code>"
+    }
+
+    fn description(&self) -> &'static str {
+        "Send text as a code block"
+    }
+
+    fn run(
+        &self,
+        args: &str,
+        _app: &mut ClickLiteApp,
+        _cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome {
+        let (lang, code) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+        let code = code.trim_start_matches([' ', '\t']);
+        let code = code.strip_prefix('\n').unwrap_or(code).trim_end();
+        if code.is_empty() {
+            return CommandOutcome::Invalid(format!("Usage: {}", self.usage()));
+        }
+
+        CommandOutcome::Send(format!("```{lang}\n{code}\n```"))
+    }
+}
+
+struct Shrug;
+
+impl SlashCommand for Shrug {
+    fn name(&self) -> &'static str {
+        "shrug"
+    }
+
+    fn args(&self) -> &'static str {
+        "[message]"
+    }
+
+    fn description(&self) -> &'static str {
+        "Append ¯\\_(ツ)_/¯ to your message"
+    }
+
+    fn run(
+        &self,
+        args: &str,
+        _app: &mut ClickLiteApp,
+        _cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome {
+        match args.trim() {
+            "" => CommandOutcome::Send(SHRUG.to_string()),
+            message => CommandOutcome::Send(format!("{message} {SHRUG}")),
+        }
+    }
+}
+
+struct Me;
+
+impl SlashCommand for Me {
+    fn name(&self) -> &'static str {
+        "me"
+    }
+
+    fn args(&self) -> &'static str {
+        "<action>"
+    }
+
+    fn description(&self) -> &'static str {
+        "Describe what you are doing"
+    }
+
+    fn run(
+        &self,
+        args: &str,
+        app: &mut ClickLiteApp,
+        _cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome {
+        let action = args.trim();
+        if action.is_empty() {
+            return CommandOutcome::Invalid(format!("Usage: {}", self.usage()));
+        }
+
+        CommandOutcome::Send(format!("_{} {action}_", app.user_display_name()))
+    }
+}
+
+struct Search;
+
+impl SlashCommand for Search {
+    fn name(&self) -> &'static str {
+        "search"
+    }
+
+    fn args(&self) -> &'static str {
+        "[query]"
+    }
+
+    fn description(&self) -> &'static str {
+        "Search messages in this chat"
+    }
+
+    fn run(
+        &self,
+        args: &str,
+        app: &mut ClickLiteApp,
+        cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome {
+        app.search_for(SearchScope::Channel, args.trim().to_string(), cx);
+        CommandOutcome::Handled
+    }
+}
+
+struct Export;
+
+impl SlashCommand for Export {
+    fn name(&self) -> &'static str {
+        "export"
+    }
+
+    fn args(&self) -> &'static str {
+        "[markdown|json|html]"
+    }
+
+    fn description(&self) -> &'static str {
+        "Export this chat's history"
+    }
+
+    fn run(
+        &self,
+        args: &str,
+        app: &mut ClickLiteApp,
+        cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome {
+        let format = match args.trim() {
+            "" => ExportFormat::Markdown,
+            value => match ExportFormat::parse(value) {
+                Some(format) => format,
+                None => return CommandOutcome::Invalid(format!("Usage: {}", self.usage())),
+            },
+        };
+
+        app.export_channel(format, cx);
+        CommandOutcome::Handled
+    }
+}
+
+struct Mute;
+
+impl SlashCommand for Mute {
+    fn name(&self) -> &'static str {
+        "mute"
+    }
+
+    fn description(&self) -> &'static str {
+        "Mute or unmute notifications for this chat"
+    }
+
+    fn run(
+        &self,
+        _args: &str,
+        app: &mut ClickLiteApp,
+        cx: &mut Context<ClickLiteApp>,
+    ) -> CommandOutcome {
+        let Some(channel) = app.selected_channel.clone() else {
+            return CommandOutcome::Handled;
+        };
+
+        app.toggle_channel_muted(channel.id.clone(), cx);
+        app.clickup_status = if app.is_channel_muted(&channel.id) {
+            format!("Muted {}", channel.display_name())
+        } else {
+            format!("Unmuted {}", channel.display_name())
+        }
+        .into();
+        CommandOutcome::Handled
+    }
+}
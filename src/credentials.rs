@@ -0,0 +1,139 @@
+use crate::api::ClickUpApi;
+use crate::error::AppError;
+use crate::settings::config_dir;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const SERVICE: &str = "click-lite";
+const ACCOUNT: &str = "clickup-access-token";
+const CREDENTIALS_FILE: &str = "credentials";
+
+#[derive(Clone, Debug)]
+pub struct CredentialStore {
+    service: String,
+    account: String,
+    file_path: Option<PathBuf>,
+}
+
+impl Default for CredentialStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CredentialStore {
+    pub fn new() -> Self {
+        Self {
+            service: SERVICE.to_string(),
+            account: ACCOUNT.to_string(),
+            file_path: config_dir().map(|dir| dir.join(CREDENTIALS_FILE)),
+        }
+    }
+
+    pub fn load(&self) -> Result<Option<String>, AppError> {
+        if let Ok(token) = self.keyring_entry().and_then(|entry| entry.get_password())
+            && !token.trim().is_empty()
+        {
+            return Ok(Some(token));
+        }
+
+        let Some(path) = self.file_path.as_ref() else {
+            return Ok(None);
+        };
+
+        match fs::read_to_string(path) {
+            Ok(token) => {
+                let token = token.trim().to_string();
+                Ok((!token.is_empty()).then_some(token))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, token: &str) -> Result<(), AppError> {
+        if self
+            .keyring_entry()
+            .and_then(|entry| entry.set_password(token))
+            .is_ok()
+        {
+            self.remove_file()?;
+            return Ok(());
+        }
+
+        let path = self.file_path.as_ref().ok_or_else(|| {
+            AppError::Credentials("no keyring or config directory available".to_string())
+        })?;
+        write_private_file(path, token)
+    }
+
+    pub fn load_api(&self) -> Result<ClickUpApi, AppError> {
+        match self.load()? {
+            Some(token) => api_for_token(token),
+            None => ClickUpApi::from_env(),
+        }
+    }
+
+    pub fn clear(&self) -> Result<(), AppError> {
+        let _ = self
+            .keyring_entry()
+            .and_then(|entry| entry.delete_credential());
+        self.remove_file()
+    }
+
+    fn keyring_entry(&self) -> keyring::Result<keyring::Entry> {
+        keyring::Entry::new(&self.service, &self.account)
+    }
+
+    fn remove_file(&self) -> Result<(), AppError> {
+        let Some(path) = self.file_path.as_ref() else {
+            return Ok(());
+        };
+
+        match fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+pub fn api_for_token(token: String) -> Result<ClickUpApi, AppError> {
+    ClickUpApi::builder(token).env_overrides()?.build()
+}
+
+fn write_private_file(path: &Path, contents: &str) -> Result<(), AppError> {
+    if let Some(parent) = path.parent() {
+        create_private_dir(parent)?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(contents.as_bytes())?;
+    Ok(())
+}
+
+fn create_private_dir(dir: &Path) -> Result<(), AppError> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(dir)?;
+    Ok(())
+}
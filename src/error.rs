@@ -1,3 +1,4 @@
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -11,9 +12,28 @@ pub enum AppError {
     #[error("API returned an error: {0}")]
     Api(String),
 
+    #[error("ClickUp returned {status}{}: {message}", code_suffix(.code))]
+    ClickUp {
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+
+    #[error("Rate limited by ClickUp, retry in {}s", .retry_after.as_secs().max(1))]
+    RateLimited { retry_after: Duration },
+
     #[error("Failed to parse response: {0}")]
     Parse(String),
 
+    #[error("ClickUp sign-in failed: {0}")]
+    OAuth(String),
+
+    #[error("Credential storage failed: {0}")]
+    Credentials(String),
+
+    #[error("Desktop notification failed: {0}")]
+    Notification(String),
+
     #[error("IO operation failed: {0}")]
     Io(#[from] std::io::Error),
 
@@ -21,6 +41,43 @@ pub enum AppError {
     Unknown(String),
 }
 
+impl AppError {
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            AppError::ClickUp { status, .. } => Some(*status),
+            AppError::Network(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(401)
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    pub fn is_server_error(&self) -> bool {
+        self.status()
+            .is_some_and(|status| (500..600).contains(&status))
+    }
+
+    pub fn is_offline(&self) -> bool {
+        matches!(self, AppError::Network(err) if err.is_connect() || err.is_timeout())
+    }
+
+    pub fn is_transient(&self) -> bool {
+        self.is_offline() || self.is_server_error()
+    }
+}
+
+fn code_suffix(code: &Option<String>) -> String {
+    code.as_ref()
+        .map(|code| format!(" ({code})"))
+        .unwrap_or_default()
+}
+
 impl From<AppError> for String {
     fn from(err: AppError) -> Self {
         err.to_string()
@@ -0,0 +1,394 @@
+use crate::api::{
+    ChannelMember, ChatMessage, ClickUpApi, ClickUpChatChannel, MessageAttachment, MessageReaction,
+    resolve_message_creators,
+};
+use crate::credentials::CredentialStore;
+use crate::error::AppError;
+use crate::settings::Settings;
+use crate::ui::normalize_chat_markdown;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "usage: click-lite export <channel id or name> [--format markdown|json|html] [--output PATH] [--workspace ID]";
+
+const HTML_STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:760px;margin:2rem auto;padding:0 1rem;color:#1f2328;background:#fff}\
+header.export{border-bottom:1px solid #d0d7de;margin-bottom:1.5rem}\
+header.export p{color:#59636e}\
+article{padding:.75rem 0;border-bottom:1px solid #eaeef2}\
+article header{display:flex;gap:.5rem;align-items:baseline}\
+article time,.meta{color:#59636e;font-size:.85rem}\
+.content p{margin:.35rem 0}\
+pre{background:#f6f8fa;padding:.75rem;overflow-x:auto;border-radius:6px}\
+code{background:#f6f8fa;padding:0 .2rem;border-radius:4px}";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Json,
+    Html,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [
+        ExportFormat::Markdown,
+        ExportFormat::Json,
+        ExportFormat::Html,
+    ];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Some(ExportFormat::Markdown),
+            "json" => Some(ExportFormat::Json),
+            "html" | "htm" => Some(ExportFormat::Html),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "Markdown",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Html => "HTML",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Json => "json",
+            ExportFormat::Html => "html",
+        }
+    }
+}
+
+pub fn export_file_name(channel: &ClickUpChatChannel, format: ExportFormat) -> String {
+    let name: String = channel
+        .display_name()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let name = name.trim_matches('-');
+    let name = if name.is_empty() {
+        channel.id.as_str()
+    } else {
+        name
+    };
+    format!("{name}.{}", format.extension())
+}
+
+pub struct ChannelExport {
+    pub channel: ClickUpChatChannel,
+    pub messages: Vec<ChatMessage>,
+    pub members: Vec<ChannelMember>,
+    pub exported_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    channel: &'a ClickUpChatChannel,
+    exported_at: String,
+    members: &'a [ChannelMember],
+    messages: Vec<JsonMessage<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonMessage<'a> {
+    id: &'a str,
+    author_id: String,
+    author: String,
+    created_at: Option<String>,
+    edited_at: Option<String>,
+    content: &'a str,
+    replies_count: u64,
+    reactions: &'a [MessageReaction],
+    attachments: &'a [MessageAttachment],
+}
+
+impl ChannelExport {
+    pub fn fetch(
+        api: &ClickUpApi,
+        workspace_id: u64,
+        channel: ClickUpChatChannel,
+    ) -> Result<Self, AppError> {
+        let mut messages = api.get_channel_history(workspace_id, &channel.id)?;
+        let members = api.get_channel_members(workspace_id, &channel.id)?;
+        resolve_message_creators(&mut messages, &members);
+
+        Ok(Self {
+            channel,
+            messages,
+            members,
+            exported_at: Utc::now(),
+        })
+    }
+
+    pub fn write_to(&self, format: ExportFormat, path: &Path) -> Result<(), AppError> {
+        fs::write(path, self.render(format)?)?;
+        Ok(())
+    }
+
+    pub fn render(&self, format: ExportFormat) -> Result<String, AppError> {
+        match format {
+            ExportFormat::Markdown => Ok(self.to_markdown()),
+            ExportFormat::Json => self.to_json(),
+            ExportFormat::Html => Ok(self.to_html()),
+        }
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "{}{}",
+            self.channel.icon_prefix(),
+            self.channel.display_name()
+        )
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "{} messages · exported {}",
+            self.messages.len(),
+            format_timestamp(self.exported_at)
+        )
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut output = format!("# {}\n\n_{}_\n", self.title(), self.summary());
+
+        for message in &self.messages {
+            let _ = write!(
+                output,
+                "\n---\n\n**{}** · {}",
+                message.creator_name(),
+                message_time(message).unwrap_or_default()
+            );
+            if message.is_edited() {
+                output.push_str(" _(edited)_");
+            }
+            output.push_str("\n\n");
+
+            if let Some(content) = message.content().filter(|c| !c.trim().is_empty()) {
+                output.push_str(&normalize_chat_markdown(content));
+                output.push_str("\n\n");
+            }
+            for attachment in &message.attachments {
+                let _ = writeln!(
+                    output,
+                    "- 📎 [{}]({})",
+                    attachment.display_name(),
+                    attachment.url
+                );
+            }
+            if message.replies_count > 0 {
+                let _ = writeln!(output, "_{}_", replies_label(message.replies_count));
+            }
+        }
+
+        output
+    }
+
+    fn to_json(&self) -> Result<String, AppError> {
+        let export = JsonExport {
+            channel: &self.channel,
+            exported_at: self.exported_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            members: &self.members,
+            messages: self
+                .messages
+                .iter()
+                .map(|message| JsonMessage {
+                    id: &message.id,
+                    author_id: message.creator_id(),
+                    author: message.creator_name(),
+                    created_at: message.date.and_then(rfc3339_from_millis),
+                    edited_at: message
+                        .is_edited()
+                        .then_some(message.date_updated)
+                        .flatten()
+                        .and_then(rfc3339_from_millis),
+                    content: message.content().unwrap_or_default(),
+                    replies_count: message.replies_count,
+                    reactions: &message.reactions,
+                    attachments: &message.attachments,
+                })
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&export)
+            .map_err(|err| AppError::Parse(format!("Failed to serialize export: {err}")))
+    }
+
+    fn to_html(&self) -> String {
+        let title = escape_html(&self.title());
+        let mut output = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>\n<header class=\"export\"><h1>{title}</h1><p>{}</p></header>\n",
+            escape_html(&self.summary())
+        );
+
+        for message in &self.messages {
+            output.push_str("<article>\n<header>");
+            let _ = write!(
+                output,
+                "<strong>{}</strong>",
+                escape_html(&message.creator_name())
+            );
+            if let (Some(datetime), Some(label)) = (
+                message.date.and_then(rfc3339_from_millis),
+                message_time(message),
+            ) {
+                let _ = write!(output, "<time datetime=\"{datetime}\">{label}</time>");
+            }
+            if message.is_edited() {
+                output.push_str("<span class=\"meta\">(edited)</span>");
+            }
+            output.push_str("</header>\n");
+
+            if let Some(content) = message.content().filter(|c| !c.trim().is_empty()) {
+                let _ = writeln!(
+                    output,
+                    "<div class=\"content\">{}</div>",
+                    markdown::to_html(&normalize_chat_markdown(content))
+                );
+            }
+            if !message.attachments.is_empty() {
+                output.push_str("<ul class=\"attachments\">");
+                for attachment in &message.attachments {
+                    let _ = write!(
+                        output,
+                        "<li><a href=\"{}\">{}</a></li>",
+                        escape_html(&attachment.url),
+                        escape_html(&attachment.display_name())
+                    );
+                }
+                output.push_str("</ul>\n");
+            }
+            if message.replies_count > 0 {
+                let _ = writeln!(
+                    output,
+                    "<p class=\"meta\">{}</p>",
+                    replies_label(message.replies_count)
+                );
+            }
+            output.push_str("</article>\n");
+        }
+
+        output.push_str("</body>\n</html>\n");
+        output
+    }
+}
+
+pub struct ExportCommand {
+    channel: String,
+    format: ExportFormat,
+    output: Option<PathBuf>,
+    workspace_id: Option<u64>,
+}
+
+impl ExportCommand {
+    pub fn parse(args: &[String]) -> Result<Self, AppError> {
+        let usage = || AppError::Config(USAGE.to_string());
+        let mut channel = None;
+        let mut format = ExportFormat::Markdown;
+        let mut output = None;
+        let mut workspace_id = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" | "-f" => {
+                    format = args
+                        .next()
+                        .and_then(|value| ExportFormat::parse(value))
+                        .ok_or_else(usage)?;
+                }
+                "--output" | "-o" => output = Some(PathBuf::from(args.next().ok_or_else(usage)?)),
+                "--workspace" | "-w" => {
+                    workspace_id = Some(
+                        args.next()
+                            .and_then(|value| value.parse::<u64>().ok())
+                            .ok_or_else(usage)?,
+                    );
+                }
+                value if !value.starts_with('-') && channel.is_none() => {
+                    channel = Some(value.to_string());
+                }
+                _ => return Err(usage()),
+            }
+        }
+
+        Ok(Self {
+            channel: channel.ok_or_else(usage)?,
+            format,
+            output,
+            workspace_id,
+        })
+    }
+
+    pub fn run(self) -> Result<PathBuf, AppError> {
+        let workspace_id = self
+            .workspace_id
+            .or(Settings::load().last_workspace_id)
+            .or_else(|| {
+                std::env::var("CLICKUP_WORKSPACE_ID")
+                    .or_else(|_| std::env::var("CLICKUP_TEAM_ID"))
+                    .ok()
+                    .and_then(|value| value.parse::<u64>().ok())
+            })
+            .ok_or_else(|| {
+                AppError::Config("No workspace selected, pass --workspace ID".to_string())
+            })?;
+
+        let api = CredentialStore::new().load_api()?;
+        let user = api.get_current_user()?;
+        let channel = api
+            .get_chat_channels(workspace_id, Some(user.id))?
+            .into_iter()
+            .find(|channel| {
+                channel.id == self.channel
+                    || channel.display_name().eq_ignore_ascii_case(&self.channel)
+            })
+            .ok_or_else(|| AppError::Config(format!("No chat named '{}'", self.channel)))?;
+
+        let path = self
+            .output
+            .unwrap_or_else(|| PathBuf::from(export_file_name(&channel, self.format)));
+        ChannelExport::fetch(&api, workspace_id, channel)?.write_to(self.format, &path)?;
+        Ok(path)
+    }
+}
+
+fn message_time(message: &ChatMessage) -> Option<String> {
+    message
+        .date
+        .and_then(datetime_from_millis)
+        .map(format_timestamp)
+}
+
+fn datetime_from_millis(millis: u64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(i64::try_from(millis).ok()?)
+}
+
+fn rfc3339_from_millis(millis: u64) -> Option<String> {
+    datetime_from_millis(millis).map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+fn format_timestamp(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+fn replies_label(count: u64) -> String {
+    match count {
+        1 => "1 reply".to_string(),
+        count => format!("{count} replies"),
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
@@ -1,4 +1,12 @@
 pub mod api;
 pub mod app;
+pub mod cache;
+pub mod commands;
+pub mod credentials;
 pub mod error;
+pub mod export;
+pub mod notifications;
+pub mod outbox;
+pub mod search;
+pub mod settings;
 pub mod ui;
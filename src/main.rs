@@ -1,9 +1,11 @@
 use std::path::PathBuf;
 
-use click_lite::app::ClickLiteApp;
+use click_lite::app::{ClickLiteApp, SearchInChannel, SearchWorkspace};
 use click_lite::error::AppError;
+use click_lite::export::ExportCommand;
 use gpui::{
-    App, Application, Bounds, SharedString, WindowBounds, WindowOptions, prelude::*, px, size,
+    App, Application, Bounds, KeyBinding, SharedString, WindowBounds, WindowOptions, prelude::*,
+    px, size,
 };
 use gpui_component::input::InputState;
 use gpui_component::{Root, Theme, ThemeRegistry};
@@ -11,8 +13,27 @@ use gpui_component::{Root, Theme, ThemeRegistry};
 fn main() {
     let _ = dotenvy::dotenv();
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("export") {
+        match ExportCommand::parse(&args[1..]).and_then(ExportCommand::run) {
+            Ok(path) => println!("Exported to {}", path.display()),
+            Err(err) => {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     Application::new().run(|cx: &mut App| {
         gpui_component::init(cx);
+        // Text inputs bind the same keys to their own find action, so override them there too.
+        cx.bind_keys([
+            KeyBinding::new("secondary-f", SearchInChannel, None),
+            KeyBinding::new("secondary-shift-f", SearchWorkspace, None),
+            KeyBinding::new("secondary-f", SearchInChannel, Some("Input")),
+            KeyBinding::new("secondary-shift-f", SearchWorkspace, Some("Input")),
+        ]);
 
         let theme_name = SharedString::from("Tokyo Night");
 
@@ -49,11 +70,22 @@ fn main() {
                             .placeholder("Select a chat to start messaging...")
                     });
 
+                    let thread_input = cx.new(|cx| {
+                        InputState::new(window, cx)
+                            .auto_grow(1, 4)
+                            .placeholder("Reply in thread...")
+                    });
+
+                    let search_input =
+                        cx.new(|cx| InputState::new(window, cx).placeholder("Search messages..."));
+
                     let mut app = ClickLiteApp::new(
                         team_id,
                         focus_handle,
                         window.window_handle(),
                         message_input,
+                        thread_input,
+                        search_input,
                         cx,
                     );
                     app.fetch_clickup_user(cx);
@@ -0,0 +1,103 @@
+use crate::error::AppError;
+use futures::stream::BoxStream;
+
+const APP_NAME: &str = "ClickLite";
+
+#[cfg(target_os = "linux")]
+const DEFAULT_ACTION: &str = "default";
+#[cfg(target_os = "linux")]
+const NOTIFICATIONS_DESTINATION: &str = "org.freedesktop.Notifications";
+#[cfg(target_os = "linux")]
+const NOTIFICATIONS_PATH: &str = "/org/freedesktop/Notifications";
+
+#[derive(Clone)]
+pub struct DesktopNotifier {
+    #[cfg(target_os = "linux")]
+    proxy: zbus::Proxy<'static>,
+}
+
+#[cfg(target_os = "linux")]
+impl DesktopNotifier {
+    pub async fn connect() -> Result<Self, AppError> {
+        let connection = zbus::Connection::session()
+            .await
+            .map_err(notification_error)?;
+        let proxy = zbus::Proxy::new(
+            &connection,
+            NOTIFICATIONS_DESTINATION,
+            NOTIFICATIONS_PATH,
+            NOTIFICATIONS_DESTINATION,
+        )
+        .await
+        .map_err(notification_error)?;
+        Ok(Self { proxy })
+    }
+
+    pub async fn notify(&self, summary: &str, body: &str) -> Result<u32, AppError> {
+        use std::collections::HashMap;
+        use zbus::zvariant::Value;
+
+        let hints: HashMap<&str, Value> = HashMap::from([
+            ("category", Value::from("im.received")),
+            ("desktop-entry", Value::from("click-lite")),
+        ]);
+        self.proxy
+            .call(
+                "Notify",
+                &(
+                    APP_NAME,
+                    0u32,
+                    "",
+                    summary,
+                    body,
+                    vec![DEFAULT_ACTION, "Open"],
+                    hints,
+                    -1i32,
+                ),
+            )
+            .await
+            .map_err(notification_error)
+    }
+
+    pub async fn clicks(&self) -> Result<BoxStream<'static, u32>, AppError> {
+        use futures::StreamExt as _;
+
+        let signals = self
+            .proxy
+            .receive_signal("ActionInvoked")
+            .await
+            .map_err(notification_error)?;
+        Ok(signals
+            .filter_map(|message| async move {
+                let (id, action): (u32, String) = message.body().deserialize().ok()?;
+                (action == DEFAULT_ACTION).then_some(id)
+            })
+            .boxed())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+impl DesktopNotifier {
+    pub async fn connect() -> Result<Self, AppError> {
+        Err(AppError::Notification(
+            "desktop notifications are only supported on Linux".to_string(),
+        ))
+    }
+
+    pub async fn notify(&self, _summary: &str, _body: &str) -> Result<u32, AppError> {
+        Err(AppError::Notification(format!(
+            "{APP_NAME} cannot show notifications on this platform"
+        )))
+    }
+
+    pub async fn clicks(&self) -> Result<BoxStream<'static, u32>, AppError> {
+        use futures::StreamExt as _;
+
+        Ok(futures::stream::empty().boxed())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn notification_error(err: zbus::Error) -> AppError {
+    AppError::Notification(err.to_string())
+}
@@ -0,0 +1,134 @@
+use crate::api::{ChatMessage, MessageAttachment};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const OUTBOX_ID_PREFIX: &str = "outbox_";
+const RETRY_BASE_DELAY_MS: u64 = 5_000;
+const RETRY_MAX_DELAY_MS: u64 = 5 * 60_000;
+const MAX_AUTO_RETRY_ATTEMPTS: u32 = 8;
+
+static NONCE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutboxStatus {
+    Sending,
+    Failed { error: String, retryable: bool },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub nonce: String,
+    pub workspace_id: u64,
+    pub channel_id: String,
+    pub content: String,
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachment>,
+    pub created_at: u64,
+    #[serde(default)]
+    pub attempts: u32,
+    #[serde(default)]
+    pub last_attempt_at: u64,
+    pub status: OutboxStatus,
+}
+
+impl OutboxEntry {
+    pub fn new(
+        workspace_id: u64,
+        channel_id: String,
+        content: String,
+        attachments: Vec<MessageAttachment>,
+    ) -> Self {
+        let created_at = now_millis();
+        Self {
+            nonce: new_nonce(created_at),
+            workspace_id,
+            channel_id,
+            content,
+            attachments,
+            created_at,
+            attempts: 0,
+            last_attempt_at: created_at,
+            status: OutboxStatus::Sending,
+        }
+    }
+
+    pub fn message_id(&self) -> String {
+        format!("{OUTBOX_ID_PREFIX}{}", self.nonce)
+    }
+
+    pub fn is_sending(&self) -> bool {
+        self.status == OutboxStatus::Sending
+    }
+
+    pub fn should_auto_retry(&self, now: u64) -> bool {
+        let retryable = matches!(
+            self.status,
+            OutboxStatus::Failed {
+                retryable: true,
+                ..
+            }
+        );
+        retryable && now >= self.last_attempt_at.saturating_add(self.retry_delay_ms())
+    }
+
+    pub fn begin_attempt(&mut self) {
+        self.attempts += 1;
+        self.last_attempt_at = now_millis();
+        self.status = OutboxStatus::Sending;
+    }
+
+    pub fn fail(&mut self, error: String, retryable: bool) {
+        self.status = OutboxStatus::Failed {
+            error,
+            retryable: retryable && self.attempts < MAX_AUTO_RETRY_ATTEMPTS,
+        };
+    }
+
+    fn retry_delay_ms(&self) -> u64 {
+        let exponent = self.attempts.saturating_sub(1).min(16);
+        RETRY_BASE_DELAY_MS
+            .saturating_mul(1 << exponent)
+            .min(RETRY_MAX_DELAY_MS)
+    }
+
+    pub fn failure(&self) -> Option<&str> {
+        match self.status {
+            OutboxStatus::Failed { ref error, .. } => Some(error),
+            OutboxStatus::Sending => None,
+        }
+    }
+
+    pub fn to_message(&self, user_id: String, username: String) -> ChatMessage {
+        ChatMessage::new_pending(
+            self.message_id(),
+            self.content.clone(),
+            self.attachments.clone(),
+            user_id,
+            username,
+        )
+    }
+}
+
+pub fn nonce_from_message_id(message_id: &str) -> Option<&str> {
+    message_id.strip_prefix(OUTBOX_ID_PREFIX)
+}
+
+fn new_nonce(created_at: u64) -> String {
+    let counter = NONCE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    format!(
+        "{created_at:x}-{:x}-{nanos:x}-{counter:x}",
+        std::process::id()
+    )
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
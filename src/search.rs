@@ -0,0 +1,177 @@
+use crate::api::ChatMessage;
+use std::collections::HashMap;
+use std::ops::Range;
+
+const SNIPPET_CONTEXT_CHARS: usize = 60;
+const PHRASE_BONUS: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchScope {
+    Channel,
+    Workspace,
+}
+
+#[derive(Clone, Debug)]
+pub struct SearchHit {
+    pub channel_id: String,
+    pub message_id: String,
+    pub author: String,
+    pub snippet: String,
+    pub highlights: Vec<Range<usize>>,
+    score: usize,
+    date: u64,
+}
+
+#[derive(Clone, Debug)]
+struct IndexedMessage {
+    id: String,
+    author: String,
+    text: String,
+    folded: String,
+    date: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    channels: HashMap<String, HashMap<String, IndexedMessage>>,
+}
+
+impl SearchIndex {
+    pub fn index_messages(&mut self, channel_id: &str, messages: &[ChatMessage]) {
+        let channel = self.channels.entry(channel_id.to_string()).or_default();
+        for message in messages.iter().filter(|m| !m.pending && m.has_text()) {
+            let text = message.preview_text();
+            channel.insert(
+                message.id.clone(),
+                IndexedMessage {
+                    id: message.id.clone(),
+                    author: message.creator_name(),
+                    folded: text.to_lowercase(),
+                    text,
+                    date: message.date.unwrap_or(0),
+                },
+            );
+        }
+    }
+
+    pub fn remove_message(&mut self, message_id: &str) {
+        for channel in self.channels.values_mut() {
+            channel.remove(message_id);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.channels.clear();
+    }
+
+    pub fn search(&self, query: &str, channel_id: Option<&str>, limit: usize) -> Vec<SearchHit> {
+        let phrase = query.trim().to_lowercase();
+        let terms: Vec<&str> = phrase.split_whitespace().collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut hits: Vec<SearchHit> = self
+            .channels
+            .iter()
+            .filter(|(id, _)| channel_id.is_none_or(|channel_id| channel_id == id.as_str()))
+            .flat_map(|(id, messages)| {
+                messages
+                    .values()
+                    .filter_map(|message| score_message(id, message, &phrase, &terms))
+            })
+            .collect();
+
+        hits.sort_by(|a, b| b.score.cmp(&a.score).then(b.date.cmp(&a.date)));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+fn score_message(
+    channel_id: &str,
+    message: &IndexedMessage,
+    phrase: &str,
+    terms: &[&str],
+) -> Option<SearchHit> {
+    let mut score = 0;
+    for term in terms {
+        let occurrences = message.folded.matches(term).count();
+        if occurrences == 0 {
+            return None;
+        }
+        score += occurrences;
+    }
+    if terms.len() > 1 && message.folded.contains(phrase) {
+        score += PHRASE_BONUS;
+    }
+
+    let (snippet, highlights) = build_snippet(message, terms);
+    Some(SearchHit {
+        channel_id: channel_id.to_string(),
+        message_id: message.id.clone(),
+        author: message.author.clone(),
+        snippet,
+        highlights,
+        score,
+        date: message.date,
+    })
+}
+
+fn build_snippet(message: &IndexedMessage, terms: &[&str]) -> (String, Vec<Range<usize>>) {
+    let text = message.text.replace('\n', " ");
+    // Lowercasing can change byte lengths outside ASCII; only highlight when offsets line up.
+    let folded = text.to_lowercase();
+    if folded.len() != text.len() {
+        let snippet: String = text.chars().take(SNIPPET_CONTEXT_CHARS * 2).collect();
+        return (snippet, Vec::new());
+    }
+
+    let first_match = terms
+        .iter()
+        .filter_map(|term| folded.find(term))
+        .min()
+        .unwrap_or(0);
+    let start = floor_char_boundary(&text, first_match.saturating_sub(SNIPPET_CONTEXT_CHARS));
+    let end = floor_char_boundary(
+        &text,
+        (first_match + SNIPPET_CONTEXT_CHARS * 2).min(text.len()),
+    );
+    let end = if end < first_match { text.len() } else { end };
+
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < text.len() { "…" } else { "" };
+    let snippet = format!("{prefix}{}{suffix}", &text[start..end]);
+
+    let window = &folded[start..end];
+    let mut highlights: Vec<Range<usize>> = terms
+        .iter()
+        .flat_map(|term| {
+            window
+                .match_indices(term)
+                .map(|(ix, matched)| {
+                    let from = prefix.len() + ix;
+                    from..from + matched.len()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    highlights.sort_by_key(|range| range.start);
+    highlights.dedup_by(|next, prev| {
+        if next.start < prev.end {
+            prev.end = prev.end.max(next.end);
+            true
+        } else {
+            false
+        }
+    });
+
+    (snippet, highlights)
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while index > 0 && !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
@@ -0,0 +1,62 @@
+use crate::error::AppError;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+const APP_DIR: &str = "click-lite";
+const SETTINGS_FILE: &str = "settings.json";
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Settings {
+    #[serde(default)]
+    pub last_workspace_id: Option<u64>,
+    #[serde(default)]
+    pub signed_out: bool,
+    #[serde(default)]
+    pub unread_since: Option<u64>,
+    #[serde(default)]
+    pub last_read: HashMap<String, u64>,
+    #[serde(default)]
+    pub muted_channels: HashSet<String>,
+    #[serde(default)]
+    pub do_not_disturb: bool,
+    #[serde(default)]
+    pub last_task_list_id: Option<String>,
+    #[serde(default)]
+    pub task_id_prefixes: HashMap<u64, HashSet<String>>,
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        settings_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), AppError> {
+        let path = settings_path()
+            .ok_or_else(|| AppError::Config("no config directory available".to_string()))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let text = serde_json::to_string_pretty(self)
+            .map_err(|err| AppError::Parse(format!("Failed to serialize settings: {err}")))?;
+        fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(APP_DIR))
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(APP_DIR))
+}
+
+fn settings_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(SETTINGS_FILE))
+}
@@ -0,0 +1,275 @@
+use crate::app::ClickLiteApp;
+use crate::ui::stable_u64_hash;
+use gpui::{Context, IntoElement, div, prelude::*, px};
+use gpui_component::ActiveTheme as _;
+use gpui_component::Sizable;
+use gpui_component::avatar::Avatar;
+use gpui_component::button::{Button, ButtonVariants as _};
+use gpui_component::skeleton::Skeleton;
+
+pub fn render_channel_details(
+    app: &mut ClickLiteApp,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    div()
+        .id("channel_details")
+        .w(px(320.0))
+        .flex_none()
+        .flex()
+        .flex_col()
+        .bg(cx.theme().background)
+        .border_l_1()
+        .border_color(cx.theme().border)
+        .child(render_details_header(cx))
+        .child(render_channel_summary(app, cx))
+        .child(render_member_list(app, cx))
+        .child(render_membership_actions(app, cx))
+}
+
+fn render_details_header(cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    div()
+        .h(px(56.0))
+        .flex_none()
+        .px_4()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .text_base()
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .child("Details"),
+        )
+        .child(
+            Button::new("close_details_button")
+                .ghost()
+                .xsmall()
+                .label("Close")
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                    this.close_channel_details(cx);
+                })),
+        )
+}
+
+fn render_channel_summary(app: &ClickLiteApp, cx: &Context<ClickLiteApp>) -> impl IntoElement {
+    let Some(channel) = app.selected_channel.as_ref() else {
+        return div().into_any_element();
+    };
+
+    let visibility = channel
+        .visibility
+        .clone()
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    div()
+        .px_4()
+        .py_3()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .child(
+            div()
+                .text_lg()
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .child(format!(
+                    "{}{}",
+                    channel.icon_prefix(),
+                    channel.display_name()
+                )),
+        )
+        .child(render_detail_row("Type", channel.channel_type.clone(), cx))
+        .child(render_detail_row("Visibility", visibility, cx))
+        .into_any_element()
+}
+
+fn render_detail_row(
+    label: &'static str,
+    value: String,
+    cx: &Context<ClickLiteApp>,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .justify_between()
+        .text_sm()
+        .child(div().text_color(cx.theme().muted_foreground).child(label))
+        .child(value)
+}
+
+fn render_member_list(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let current_user_id = app.user.as_ref().map(|u| u.id.to_string());
+    let is_dm = app
+        .selected_channel
+        .as_ref()
+        .is_some_and(|channel| channel.channel_type == "DM");
+
+    let members = if app.channel_members_loading && app.channel_members.is_empty() {
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .children((0..4).map(|_| Skeleton::new().h(px(32.)).w_full()))
+            .into_any_element()
+    } else {
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .children(app.channel_members.iter().map(|member| {
+                let is_self = current_user_id.as_deref() == Some(member.id.as_str());
+                let member_clone = member.clone();
+                div()
+                    .id(("member", stable_u64_hash(&member.id)))
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        Avatar::new()
+                            .name(member.display_name())
+                            .with_size(gpui_component::Size::Small),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .flex()
+                            .flex_col()
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_1()
+                                    .text_sm()
+                                    .child(member.display_name())
+                                    .when_some(member.role.clone(), |this, role| {
+                                        this.child(
+                                            div()
+                                                .text_xs()
+                                                .px_1()
+                                                .rounded_sm()
+                                                .bg(cx.theme().muted.opacity(0.25))
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(role.to_lowercase()),
+                                        )
+                                    }),
+                            )
+                            .when_some(member.email.clone(), |this, email| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .truncate()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(email),
+                                )
+                            }),
+                    )
+                    .when(!is_self && !is_dm, |this| {
+                        this.child(
+                            Button::new("remove_member")
+                                .ghost()
+                                .xsmall()
+                                .label("Remove")
+                                .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                    this.confirm_remove_member(member_clone.clone(), cx);
+                                })),
+                        )
+                    })
+            }))
+            .into_any_element()
+    };
+
+    div()
+        .id("channel_members")
+        .flex_1()
+        .min_h_0()
+        .overflow_y_scroll()
+        .px_4()
+        .py_3()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_weight(gpui::FontWeight::SEMIBOLD)
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!("MEMBERS ({})", app.channel_members.len())),
+                )
+                .when(!is_dm, |this| {
+                    this.child(
+                        Button::new("add_members_button")
+                            .ghost()
+                            .xsmall()
+                            .label("Add")
+                            .loading(app.team_members_loading)
+                            .on_click(cx.listener(|this, _ev, _window, cx| {
+                                this.open_add_members_dialog(cx);
+                            })),
+                    )
+                }),
+        )
+        .child(members)
+}
+
+fn render_membership_actions(
+    app: &ClickLiteApp,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    let is_dm = app
+        .selected_channel
+        .as_ref()
+        .is_some_and(|channel| channel.channel_type == "DM");
+    let channel_id = app
+        .selected_channel
+        .as_ref()
+        .map(|channel| channel.id.clone())
+        .unwrap_or_default();
+    let is_muted = app.is_channel_muted(&channel_id);
+
+    div()
+        .p_3()
+        .flex()
+        .gap_2()
+        .border_t_1()
+        .border_color(cx.theme().border)
+        .child(
+            Button::new("mute_channel_button")
+                .outline()
+                .small()
+                .flex_1()
+                .label(if is_muted { "Unmute" } else { "Mute" })
+                .on_click(cx.listener(move |this, _ev, _window, cx| {
+                    this.toggle_channel_muted(channel_id.clone(), cx);
+                })),
+        )
+        .child(
+            Button::new("unfollow_channel_button")
+                .outline()
+                .small()
+                .flex_1()
+                .label("Unfollow")
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                    this.confirm_unfollow_channel(cx);
+                })),
+        )
+        .when(!is_dm, |this| {
+            this.child(
+                Button::new("leave_channel_button")
+                    .danger()
+                    .small()
+                    .flex_1()
+                    .label("Leave")
+                    .on_click(cx.listener(|this, _ev, _window, cx| {
+                        this.confirm_leave_channel(cx);
+                    })),
+            )
+        })
+}
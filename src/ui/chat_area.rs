@@ -1,18 +1,34 @@
+use crate::api::{MENTION_REGEX, reaction_emoji};
 use crate::app::ClickLiteApp;
 use crate::ui::stable_u64_hash;
-use gpui::{Context, IntoElement, Window, div, prelude::*, px};
+use crate::ui::task_card::render_task_cards;
+use gpui::{Context, IntoElement, ObjectFit, Window, div, img, prelude::*, px};
 use gpui_component::ActiveTheme as _;
 use gpui_component::Disableable;
 use gpui_component::Sizable;
 use gpui_component::avatar::Avatar;
 use gpui_component::button::{Button, ButtonVariants as _};
-use gpui_component::input::Input;
+use gpui_component::input::{Enter, Escape, IndentInline, Input, MoveDown, MoveUp};
+use gpui_component::menu::{ContextMenuExt as _, DropdownMenu as _, PopupMenuItem};
 use gpui_component::skeleton::Skeleton;
 use gpui_component::text::{TextView, TextViewStyle};
 use regex::Regex;
 use std::iter::repeat_n;
 use std::sync::LazyLock;
 
+const HISTORY_LOAD_THRESHOLD: f32 = 48.0;
+const ATTACHMENT_THUMBNAIL_SIZE: f32 = 240.0;
+const PICKER_REACTIONS: [&str; 8] = [
+    "thumbsup",
+    "heart",
+    "joy",
+    "tada",
+    "open_mouth",
+    "cry",
+    "eyes",
+    "white_check_mark",
+];
+
 static LINK_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\[\s*([^\]]*?)\s*\]\(([^)]+)\)").expect("Invalid regex"));
 
@@ -43,11 +59,19 @@ fn render_messages(
         .min_h_0()
         .overflow_y_scroll()
         .track_scroll(&scroll_handle)
+        .on_scroll_wheel(cx.listener(move |this, _ev, _window, cx| {
+            if this.has_older_messages() && scroll_handle.offset().y > -px(HISTORY_LOAD_THRESHOLD) {
+                this.load_older_messages(cx);
+            }
+        }))
         .p_4()
-        .child(if app.selected_channel.is_some() {
+        .flex()
+        .flex_col()
+        .gap_3()
+        .children(if app.selected_channel.is_some() {
             render_message_list(app, window, cx)
         } else {
-            render_welcome_message(cx)
+            vec![render_welcome_message(cx)]
         })
 }
 
@@ -55,7 +79,7 @@ fn render_message_list(
     app: &ClickLiteApp,
     window: &mut Window,
     cx: &mut Context<ClickLiteApp>,
-) -> gpui::AnyElement {
+) -> Vec<gpui::AnyElement> {
     let current_user_id = app.user.as_ref().map(|u| u.id.to_string());
     let messages: Vec<_> = app.messages().collect();
     let mut rendered_messages = Vec::with_capacity(messages.len());
@@ -64,32 +88,52 @@ fn render_message_list(
             .as_ref()
             .map(|id| *id == msg.creator_id())
             .unwrap_or(false);
-        rendered_messages
-            .push(render_message_bubble(msg, is_own_message, window, cx).into_any_element());
+        rendered_messages.push(
+            render_message_bubble(
+                app,
+                msg,
+                is_own_message,
+                current_user_id.as_deref(),
+                window,
+                cx,
+            )
+            .into_any_element(),
+        );
     }
 
-    let has_messages = !rendered_messages.is_empty();
-
-    div()
-        .flex()
-        .flex_col()
-        .w_full()
-        .gap_3()
-        .when(app.messages_loading, |this| {
-            this.child(render_messages_loading_placeholder(cx))
-        })
-        .when(!app.messages_loading && !has_messages, |this| {
-            this.child(
-                div().p_4().rounded_lg().bg(cx.theme().secondary).child(
+    // Messages are direct children of the scroll container so search results can scroll to them.
+    let mut items = Vec::with_capacity(rendered_messages.len() + 1);
+    if app.history_loading {
+        items.push(
+            div()
+                .w_full()
+                .flex()
+                .justify_center()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child("Loading older messages…")
+                .into_any_element(),
+        );
+    }
+    if rendered_messages.is_empty() {
+        items.push(if app.messages_loading {
+            render_messages_loading_placeholder(cx)
+        } else {
+            div()
+                .p_4()
+                .rounded_lg()
+                .bg(cx.theme().secondary)
+                .child(
                     div()
                         .text_sm()
                         .text_color(cx.theme().muted_foreground)
                         .child("This is the beginning of the conversation."),
-                ),
-            )
-        })
-        .children(rendered_messages)
-        .into_any_element()
+                )
+                .into_any_element()
+        });
+    }
+    items.extend(rendered_messages);
+    items
 }
 
 fn render_messages_loading_placeholder(cx: &Context<ClickLiteApp>) -> gpui::AnyElement {
@@ -184,8 +228,10 @@ fn render_message_skeleton(
 }
 
 fn render_message_bubble(
+    app: &ClickLiteApp,
     msg: &crate::api::ChatMessage,
     is_own_message: bool,
+    current_user_id: Option<&str>,
     window: &mut Window,
     cx: &mut Context<ClickLiteApp>,
 ) -> impl IntoElement {
@@ -193,6 +239,80 @@ fn render_message_bubble(
     let msg_id = stable_u64_hash(&msg.id);
     let message_content = msg.display_content();
     let is_pending = msg.pending;
+    let failure = app.outbox_failure(&msg.id).map(str::to_string);
+    let is_failed = failure.is_some();
+    let is_edited = msg.is_edited();
+    let can_edit = is_own_message && !is_pending;
+    let replies_count = msg.replies_count;
+    let message_id = msg.id.clone();
+    let is_highlighted = app.highlighted_message_id.as_deref() == Some(msg.id.as_str());
+
+    let bubble = div()
+        .px_3()
+        .py_2()
+        .rounded_lg()
+        .when(is_highlighted, |this| {
+            this.border_2().border_color(cx.theme().warning)
+        })
+        .bg(if is_own_message {
+            cx.theme().primary
+        } else {
+            cx.theme().secondary
+        })
+        .text_sm()
+        .text_color(if is_own_message {
+            cx.theme().primary_foreground
+        } else {
+            cx.theme().secondary_foreground
+        })
+        .max_w(px(500.0))
+        .child(render_message_content(
+            msg_id,
+            &message_content,
+            is_own_message,
+            window,
+            cx,
+        ));
+    let bubble = if !is_pending {
+        let app_entity = cx.entity();
+        let message_id = message_id.clone();
+        bubble
+            .context_menu(move |menu, _window, _cx| {
+                let task_entity = app_entity.clone();
+                let task_id = message_id.clone();
+                let menu = menu.item(PopupMenuItem::new("Create task from message").on_click(
+                    move |_ev, _window, cx| {
+                        task_entity.update(cx, |this, cx| {
+                            this.open_create_task_dialog(task_id.clone(), cx);
+                        });
+                    },
+                ));
+                if !can_edit {
+                    return menu;
+                }
+
+                let edit_entity = app_entity.clone();
+                let edit_id = message_id.clone();
+                let delete_entity = app_entity.clone();
+                let delete_id = message_id.clone();
+                menu.separator()
+                    .item(
+                        PopupMenuItem::new("Edit").on_click(move |_ev, _window, cx| {
+                            edit_entity.update(cx, |this, cx| this.start_editing(&edit_id, cx));
+                        }),
+                    )
+                    .item(
+                        PopupMenuItem::new("Delete").on_click(move |_ev, _window, cx| {
+                            delete_entity.update(cx, |this, cx| {
+                                this.confirm_delete_message(delete_id.clone(), cx);
+                            });
+                        }),
+                    )
+            })
+            .into_any_element()
+    } else {
+        bubble.into_any_element()
+    };
 
     // Note: Profile pictures for message creators are not available without ClickUp Enterprise plan
     let avatar = Avatar::new()
@@ -205,7 +325,7 @@ fn render_message_bubble(
         .gap_3()
         .w_full()
         .when(is_own_message, |this| this.flex_row_reverse())
-        .when(is_pending, |this| this.opacity(0.6))
+        .when(is_pending && !is_failed, |this| this.opacity(0.6))
         .child(avatar)
         .child(
             div()
@@ -228,44 +348,273 @@ fn render_message_bubble(
                                 .text_color(cx.theme().foreground)
                                 .child(username),
                         )
-                        .when(is_pending, |this| {
+                        .when(is_pending && !is_failed, |this| {
                             this.child(
                                 div()
                                     .text_xs()
                                     .text_color(cx.theme().muted_foreground)
                                     .child("Sending..."),
                             )
+                        })
+                        .when_some(failure, |this, failure| {
+                            let retry_id = message_id.clone();
+                            let discard_id = message_id.clone();
+                            this.child(
+                                div()
+                                    .id(("failed_msg", msg_id))
+                                    .text_xs()
+                                    .text_color(cx.theme().danger)
+                                    .child("Failed —")
+                                    .tooltip(move |window, cx| {
+                                        gpui_component::tooltip::Tooltip::new(failure.clone())
+                                            .build(window, cx)
+                                    }),
+                            )
+                            .child(
+                                Button::new(("retry_msg", msg_id))
+                                    .ghost()
+                                    .xsmall()
+                                    .label("Retry")
+                                    .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                        this.retry_outbox_message(&retry_id, cx);
+                                    })),
+                            )
+                            .child(
+                                Button::new(("discard_msg", msg_id))
+                                    .ghost()
+                                    .xsmall()
+                                    .label("Discard")
+                                    .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                        this.discard_outbox_message(&discard_id, cx);
+                                    })),
+                            )
+                        })
+                        .when(is_edited, |this| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child("(edited)"),
+                            )
+                        })
+                        .when(!is_pending, |this| {
+                            let message_id = message_id.clone();
+                            this.child(
+                                Button::new(("reply_msg", msg_id))
+                                    .ghost()
+                                    .xsmall()
+                                    .label("Reply")
+                                    .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                        this.open_thread(&message_id, cx);
+                                    })),
+                            )
+                        })
+                        .when(can_edit, |this| {
+                            let message_id = message_id.clone();
+                            this.child(
+                                Button::new(("edit_msg", msg_id))
+                                    .ghost()
+                                    .xsmall()
+                                    .label("Edit")
+                                    .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                        this.start_editing(&message_id, cx);
+                                    })),
+                            )
                         }),
                 )
-                .child(
+                .when(msg.has_text() || msg.attachments.is_empty(), |this| {
+                    this.child(bubble)
+                })
+                .when(!msg.attachments.is_empty(), |this| {
+                    this.child(render_attachments(app, msg, is_own_message, cx))
+                })
+                .children(render_task_cards(app, msg, cx))
+                .when(replies_count > 0, |this| {
+                    let message_id = message_id.clone();
+                    this.child(
+                        Button::new(("view_thread", msg_id))
+                            .ghost()
+                            .xsmall()
+                            .label(format!(
+                                "{replies_count} {} · View thread",
+                                if replies_count == 1 {
+                                    "reply"
+                                } else {
+                                    "replies"
+                                }
+                            ))
+                            .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                this.open_thread(&message_id, cx);
+                            })),
+                    )
+                })
+                .when(!is_pending, |this| {
+                    this.child(render_reactions(
+                        msg,
+                        msg_id,
+                        current_user_id,
+                        is_own_message,
+                        cx,
+                    ))
+                }),
+        )
+}
+
+pub(super) fn render_attachments(
+    app: &ClickLiteApp,
+    msg: &crate::api::ChatMessage,
+    is_own_message: bool,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .items_start()
+        .when(is_own_message, |this| this.items_end())
+        .children(msg.attachments.iter().enumerate().map(|(ix, attachment)| {
+            let name = attachment.display_name();
+            match app.attachment_image(attachment.preview_url()) {
+                Some(image) if attachment.is_image() => {
+                    let url = attachment.url.clone();
+                    div()
+                        .id(("attachment", ix))
+                        .cursor_pointer()
+                        .rounded_lg()
+                        .overflow_hidden()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .on_click(move |_ev, _window, cx| cx.open_url(&url))
+                        .child(
+                            img(image)
+                                .max_w(px(ATTACHMENT_THUMBNAIL_SIZE))
+                                .max_h(px(ATTACHMENT_THUMBNAIL_SIZE))
+                                .object_fit(ObjectFit::Contain),
+                        )
+                        .into_any_element()
+                }
+                _ => {
+                    let attachment = attachment.clone();
                     div()
+                        .id(("attachment", ix))
+                        .flex()
+                        .items_center()
+                        .gap_2()
                         .px_3()
                         .py_2()
                         .rounded_lg()
-                        .bg(if is_own_message {
-                            cx.theme().primary
-                        } else {
-                            cx.theme().secondary
-                        })
-                        .text_sm()
-                        .text_color(if is_own_message {
-                            cx.theme().primary_foreground
-                        } else {
-                            cx.theme().secondary_foreground
-                        })
-                        .max_w(px(500.0))
-                        .child(render_message_content(
-                            msg_id,
-                            &message_content,
-                            is_own_message,
-                            window,
-                            cx,
-                        )),
-                ),
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .bg(cx.theme().secondary)
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .min_w_0()
+                                .child(div().text_sm().truncate().child(name))
+                                .when_some(attachment.size, |this, size| {
+                                    this.child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(format_file_size(size)),
+                                    )
+                                }),
+                        )
+                        .child(
+                            Button::new(("save_attachment", ix))
+                                .ghost()
+                                .xsmall()
+                                .label("Save as…")
+                                .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                    this.save_attachment(attachment.clone(), cx);
+                                })),
+                        )
+                        .into_any_element()
+                }
+            }
+        }))
+}
+
+fn format_file_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} {}", UNITS[unit])
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+fn render_reactions(
+    msg: &crate::api::ChatMessage,
+    msg_id: u64,
+    current_user_id: Option<&str>,
+    is_own_message: bool,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    let app_entity = cx.entity();
+    let can_react = current_user_id.is_some();
+
+    div()
+        .flex()
+        .flex_wrap()
+        .items_center()
+        .gap_1()
+        .when(is_own_message, |this| this.flex_row_reverse())
+        .children(
+            msg.reaction_summary(current_user_id)
+                .into_iter()
+                .enumerate()
+                .map(|(ix, summary)| {
+                    let message_id = msg.id.clone();
+                    let reaction = summary.reaction.clone();
+                    Button::new(("reaction", ix))
+                        .xsmall()
+                        .when(summary.reacted, |this| this.primary())
+                        .when(!summary.reacted, |this| this.outline())
+                        .label(format!(
+                            "{} {}",
+                            reaction_emoji(&summary.reaction),
+                            summary.count
+                        ))
+                        .disabled(!can_react)
+                        .on_click(cx.listener(move |this, _ev, _window, cx| {
+                            this.toggle_reaction(&message_id, &reaction, cx);
+                        }))
+                }),
         )
+        .when(can_react, |this| {
+            let message_id = msg.id.clone();
+            this.child(
+                Button::new(("add_reaction", msg_id))
+                    .ghost()
+                    .xsmall()
+                    .label("☺+")
+                    .dropdown_menu(move |menu, _window, _cx| {
+                        PICKER_REACTIONS.iter().fold(menu, |menu, reaction| {
+                            let app_entity = app_entity.clone();
+                            let message_id = message_id.clone();
+                            menu.item(PopupMenuItem::new(reaction_emoji(reaction)).on_click(
+                                move |_ev, _window, cx| {
+                                    app_entity.update(cx, |this, cx| {
+                                        this.toggle_reaction(&message_id, reaction, cx);
+                                    });
+                                },
+                            ))
+                        })
+                    }),
+            )
+        })
 }
 
-fn render_message_content(
+pub(super) fn render_message_content(
     msg_id: u64,
     content: &str,
     is_own_message: bool,
@@ -287,8 +636,9 @@ fn render_message_content(
         .into_any_element()
 }
 
-fn normalize_chat_markdown(content: &str) -> String {
+pub(crate) fn normalize_chat_markdown(content: &str) -> String {
     let content = fix_clickup_links(content);
+    let content = highlight_mentions(&content);
 
     let mut output = String::with_capacity(content.len() * 2);
     let mut in_fence = false;
@@ -332,6 +682,14 @@ fn normalize_chat_markdown(content: &str) -> String {
     output
 }
 
+fn highlight_mentions(content: &str) -> String {
+    MENTION_REGEX
+        .replace_all(content, |caps: &regex::Captures| {
+            format!("`@{}`", caps[1].trim())
+        })
+        .into_owned()
+}
+
 fn fix_clickup_links(content: &str) -> String {
     let result = LINK_REGEX.replace_all(content, |caps: &regex::Captures| {
         let display_text = &caps[1];
@@ -441,6 +799,7 @@ fn render_input_area(
             .as_ref()
             .trim()
             .is_empty();
+    let is_editing = app.editing_message_id.is_some();
     let app_entity = cx.entity();
 
     div()
@@ -450,20 +809,208 @@ fn render_input_area(
         .border_t_1()
         .border_color(cx.theme().border)
         .flex()
-        .gap_2()
-        .child(render_text_input(app))
-        .when(has_channel, |this| {
+        .flex_col()
+        .gap_1()
+        .capture_action(cx.listener(|this, _: &MoveUp, _window, cx| {
+            if this.mentions_open() {
+                this.move_mention_selection(-1, cx);
+                cx.stop_propagation();
+            } else if this.commands_open() {
+                this.move_command_selection(-1, cx);
+                cx.stop_propagation();
+            }
+        }))
+        .capture_action(cx.listener(|this, _: &MoveDown, _window, cx| {
+            if this.mentions_open() {
+                this.move_mention_selection(1, cx);
+                cx.stop_propagation();
+            } else if this.commands_open() {
+                this.move_command_selection(1, cx);
+                cx.stop_propagation();
+            }
+        }))
+        .capture_action(cx.listener(|this, _: &Enter, _window, cx| {
+            if this.mentions_open() {
+                this.accept_mention(None, cx);
+                cx.stop_propagation();
+            } else if this.commands_open() {
+                this.accept_command(None, cx);
+                cx.stop_propagation();
+            }
+        }))
+        .capture_action(cx.listener(|this, _: &IndentInline, _window, cx| {
+            if this.mentions_open() {
+                this.accept_mention(None, cx);
+                cx.stop_propagation();
+            } else if this.commands_open() {
+                this.accept_command(None, cx);
+                cx.stop_propagation();
+            }
+        }))
+        .capture_action(cx.listener(|this, _: &Escape, _window, cx| {
+            if this.mentions_open() {
+                this.dismiss_mentions(cx);
+                cx.stop_propagation();
+            } else if this.commands_open() {
+                this.dismiss_commands(cx);
+                cx.stop_propagation();
+            }
+        }))
+        .on_action(cx.listener(|this, _: &Escape, _window, cx| {
+            this.cancel_editing(cx);
+        }))
+        .when(app.mentions_open(), |this| {
+            this.child(render_mention_popover(app, cx))
+        })
+        .when(app.commands_open(), |this| {
+            this.child(render_command_popover(app, cx))
+        })
+        .when(is_editing, |this| {
             this.child(
-                Button::new("send_button")
-                    .primary()
-                    .label("Send")
-                    .h(px(38.0))
-                    .disabled(!can_send)
-                    .on_click(move |_ev, _window, cx| {
-                        app_entity.update(cx, |this, cx| this.send_message(cx));
-                    }),
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("Editing message · Enter to save, Esc to cancel")
+                    .child(
+                        Button::new("cancel_edit_button")
+                            .ghost()
+                            .xsmall()
+                            .label("Cancel")
+                            .on_click(cx.listener(|this, _ev, _window, cx| {
+                                this.cancel_editing(cx);
+                            })),
+                    ),
             )
         })
+        .child(
+            div()
+                .flex()
+                .gap_2()
+                .child(render_text_input(app))
+                .when(has_channel, |this| {
+                    this.child(
+                        Button::new("attach_button")
+                            .ghost()
+                            .label("Attach")
+                            .h(px(38.0))
+                            .loading(app.uploading_attachments > 0)
+                            .disabled(is_editing || app.uploading_attachments > 0)
+                            .on_click(cx.listener(|this, _ev, _window, cx| {
+                                this.attach_files(cx);
+                            })),
+                    )
+                    .child(
+                        Button::new("send_button")
+                            .primary()
+                            .label(if is_editing { "Save" } else { "Send" })
+                            .h(px(38.0))
+                            .disabled(!can_send)
+                            .on_click(move |_ev, _window, cx| {
+                                app_entity.update(cx, |this, cx| {
+                                    if this.editing_message_id.is_some() {
+                                        this.save_edit(cx);
+                                    } else {
+                                        this.send_message(cx);
+                                    }
+                                });
+                            }),
+                    )
+                }),
+        )
+}
+
+fn render_mention_popover(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    div()
+        .id("mention_popover")
+        .flex()
+        .flex_col()
+        .p_1()
+        .rounded_md()
+        .border_1()
+        .border_color(cx.theme().border)
+        .bg(cx.theme().popover)
+        .shadow_md()
+        .children(
+            app.mention_matches()
+                .into_iter()
+                .enumerate()
+                .map(|(ix, member)| {
+                    let is_selected = ix == app.mention_selected;
+                    div()
+                        .id(("mention", ix))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .px_2()
+                        .py_1()
+                        .rounded_sm()
+                        .cursor_pointer()
+                        .when(is_selected, |this| this.bg(cx.theme().accent))
+                        .hover(|this| this.bg(cx.theme().accent))
+                        .child(
+                            Avatar::new()
+                                .name(member.display_name())
+                                .with_size(gpui_component::Size::XSmall),
+                        )
+                        .child(div().text_sm().child(member.display_name()))
+                        .when_some(member.email.clone(), |this, email| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(email),
+                            )
+                        })
+                        .on_click(cx.listener(move |this, _ev, _window, cx| {
+                            this.accept_mention(Some(ix), cx);
+                        }))
+                }),
+        )
+}
+
+fn render_command_popover(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    div()
+        .id("command_popover")
+        .flex()
+        .flex_col()
+        .p_1()
+        .rounded_md()
+        .border_1()
+        .border_color(cx.theme().border)
+        .bg(cx.theme().popover)
+        .shadow_md()
+        .children(
+            app.command_matches()
+                .into_iter()
+                .enumerate()
+                .map(|(ix, command)| {
+                    let is_selected = ix == app.command_selected;
+                    div()
+                        .id(("command", ix))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .px_2()
+                        .py_1()
+                        .rounded_sm()
+                        .cursor_pointer()
+                        .when(is_selected, |this| this.bg(cx.theme().accent))
+                        .hover(|this| this.bg(cx.theme().accent))
+                        .child(div().text_sm().child(command.usage()))
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(command.description()),
+                        )
+                        .on_click(cx.listener(move |this, _ev, _window, cx| {
+                            this.accept_command(Some(ix), cx);
+                        }))
+                }),
+        )
 }
 
 fn render_text_input(app: &ClickLiteApp) -> impl IntoElement {
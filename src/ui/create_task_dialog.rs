@@ -0,0 +1,218 @@
+use crate::api::{ClickUpUser, NewTask, TaskList};
+use crate::ui::people_picker::{PeoplePicker, PeoplePickerEvent};
+use crate::ui::stable_u64_hash;
+use gpui::{Context, Entity, IntoElement, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::ActiveTheme as _;
+use gpui_component::Sizable;
+use gpui_component::input::{Input, InputEvent, InputState};
+
+const TASK_NAME_MAX_CHARS: usize = 80;
+
+#[derive(Clone, Debug)]
+pub struct CreateTaskRequest {
+    pub list_id: String,
+    pub task: NewTask,
+}
+
+pub struct CreateTaskForm {
+    lists: Vec<TaskList>,
+    selected_list: Option<String>,
+    list_search_input: Entity<InputState>,
+    name_input: Entity<InputState>,
+    description_input: Entity<InputState>,
+    people: Entity<PeoplePicker>,
+    error: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl CreateTaskForm {
+    pub fn new(
+        lists: Vec<TaskList>,
+        selected_list: Option<String>,
+        members: Vec<ClickUpUser>,
+        name: String,
+        description: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let list_search_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("Search lists..."));
+        let name_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Task name")
+                .default_value(name)
+        });
+        let description_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .auto_grow(3, 8)
+                .placeholder("Description")
+                .default_value(description)
+        });
+        let people = cx.new(|cx| PeoplePicker::new(members, window, cx));
+
+        let clear_error = |this: &mut Self, _, event: &InputEvent, cx: &mut Context<Self>| {
+            if let InputEvent::Change = event {
+                this.error = None;
+                cx.notify();
+            }
+        };
+        let subscriptions = vec![
+            cx.subscribe(&list_search_input, clear_error),
+            cx.subscribe(&name_input, clear_error),
+            cx.subscribe(&people, |this, _, _: &PeoplePickerEvent, cx| {
+                this.error = None;
+                cx.notify();
+            }),
+        ];
+
+        let selected_list = selected_list.filter(|id| lists.iter().any(|list| list.id == *id));
+
+        Self {
+            lists,
+            selected_list,
+            list_search_input,
+            name_input,
+            description_input,
+            people,
+            error: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    pub fn request(&mut self, cx: &mut Context<Self>) -> Option<CreateTaskRequest> {
+        let name = self.name_input.read(cx).value().trim().to_string();
+        let result = match self.selected_list.clone() {
+            None => Err("Pick a list for the task."),
+            Some(_) if name.is_empty() => Err("Give the task a name."),
+            Some(list_id) => Ok(CreateTaskRequest {
+                list_id,
+                task: NewTask {
+                    name,
+                    description: self.description_input.read(cx).value().trim().to_string(),
+                    assignees: self.people.read(cx).selected_ids(),
+                },
+            }),
+        };
+
+        match result {
+            Ok(request) => Some(request),
+            Err(message) => {
+                self.error = Some(message.into());
+                cx.notify();
+                None
+            }
+        }
+    }
+
+    fn select_list(&mut self, list_id: String, cx: &mut Context<Self>) {
+        self.selected_list = Some(list_id);
+        self.error = None;
+        cx.notify();
+    }
+
+    fn render_list_picker(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let query = self
+            .list_search_input
+            .read(cx)
+            .value()
+            .trim()
+            .to_lowercase();
+        let lists: Vec<(String, SharedString, bool)> = self
+            .lists
+            .iter()
+            .filter(|list| query.is_empty() || list.path.to_lowercase().contains(&query))
+            .map(|list| {
+                (
+                    list.id.clone(),
+                    list.path.clone().into(),
+                    self.selected_list.as_deref() == Some(list.id.as_str()),
+                )
+            })
+            .collect();
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(Input::new(&self.list_search_input).small())
+            .child(
+                div()
+                    .id("task_list_picker")
+                    .h(px(140.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .when(lists.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("No lists found"),
+                        )
+                    })
+                    .children(lists.into_iter().map(|(list_id, path, selected)| {
+                        div()
+                            .id(("task_list", stable_u64_hash(&list_id)))
+                            .px_2()
+                            .py_1()
+                            .rounded_md()
+                            .text_sm()
+                            .truncate()
+                            .cursor_pointer()
+                            .when(selected, |this| {
+                                this.bg(cx.theme().primary)
+                                    .text_color(cx.theme().primary_foreground)
+                            })
+                            .when(!selected, |this| {
+                                this.hover(|this| this.bg(cx.theme().accent))
+                            })
+                            .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                this.select_list(list_id.clone(), cx);
+                            }))
+                            .child(path)
+                    })),
+            )
+    }
+}
+
+fn render_section_label(label: &'static str, cx: &Context<CreateTaskForm>) -> impl IntoElement {
+    div()
+        .text_xs()
+        .font_weight(gpui::FontWeight::SEMIBOLD)
+        .text_color(cx.theme().muted_foreground)
+        .child(label)
+}
+
+pub fn task_name_from_content(content: &str) -> String {
+    let first_line = content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    if first_line.chars().count() <= TASK_NAME_MAX_CHARS {
+        return first_line.to_string();
+    }
+
+    let truncated: String = first_line.chars().take(TASK_NAME_MAX_CHARS - 1).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+impl Render for CreateTaskForm {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(render_section_label("LIST", cx))
+            .child(self.render_list_picker(cx))
+            .child(render_section_label("TASK", cx))
+            .child(Input::new(&self.name_input))
+            .child(Input::new(&self.description_input))
+            .child(render_section_label("ASSIGNEES (OPTIONAL)", cx))
+            .child(self.people.clone())
+            .when_some(self.error.clone(), |this, error| {
+                this.child(div().text_sm().text_color(cx.theme().danger).child(error))
+            })
+    }
+}
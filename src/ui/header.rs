@@ -1,6 +1,10 @@
 use crate::app::ClickLiteApp;
+use crate::export::ExportFormat;
 use gpui::{Context, IntoElement, div, prelude::*, px};
 use gpui_component::ActiveTheme as _;
+use gpui_component::Sizable;
+use gpui_component::button::{Button, ButtonVariants as _};
+use gpui_component::menu::{DropdownMenu as _, PopupMenuItem};
 
 pub fn render_header(app: &mut ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
     div()
@@ -15,13 +19,50 @@ pub fn render_header(app: &mut ClickLiteApp, cx: &mut Context<ClickLiteApp>) ->
         .border_b_1()
         .border_color(cx.theme().border)
         .child(render_channel_title(app, cx))
+        .when(app.selected_channel.is_some(), |this| {
+            this.child(render_export_menu(app, cx))
+        })
 }
 
-fn render_channel_title(app: &ClickLiteApp, cx: &Context<ClickLiteApp>) -> impl IntoElement {
+fn render_export_menu(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let app_entity = cx.entity();
+
+    Button::new("export_button")
+        .ghost()
+        .small()
+        .label("Export")
+        .loading(app.exporting)
+        .dropdown_menu(move |menu, _window, _cx| {
+            ExportFormat::ALL.iter().fold(menu, |menu, format| {
+                let app_entity = app_entity.clone();
+                let format = *format;
+                menu.item(
+                    PopupMenuItem::new(format!("Export as {}", format.label())).on_click(
+                        move |_ev, _window, cx| {
+                            app_entity.update(cx, |this, cx| this.export_channel(format, cx));
+                        },
+                    ),
+                )
+            })
+        })
+}
+
+fn render_channel_title(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
     div()
+        .id("channel_title")
         .flex()
         .items_center()
         .gap_2()
+        .when(app.selected_channel.is_some(), |this| {
+            this.cursor_pointer()
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .hover(|this| this.bg(cx.theme().accent.opacity(0.5)))
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                    this.toggle_channel_details(cx);
+                }))
+        })
         .child(
             div()
                 .text_base()
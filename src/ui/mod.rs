@@ -1,10 +1,24 @@
+mod channel_details;
 mod chat_area;
+mod create_task_dialog;
 mod header;
+mod new_chat_dialog;
+mod people_picker;
+mod search_panel;
 mod sidebar;
+mod task_card;
+mod thread_panel;
 
+pub use channel_details::render_channel_details;
+pub(crate) use chat_area::normalize_chat_markdown;
 pub use chat_area::render_chat_area;
+pub use create_task_dialog::{CreateTaskForm, CreateTaskRequest, task_name_from_content};
 pub use header::render_header;
+pub use new_chat_dialog::{NewChatForm, NewChatRequest};
+pub use people_picker::PeoplePicker;
+pub use search_panel::render_search_panel;
 pub use sidebar::render_sidebar;
+pub use thread_panel::render_thread_panel;
 
 pub fn stable_u64_hash(value: &str) -> u64 {
     use std::hash::{Hash, Hasher};
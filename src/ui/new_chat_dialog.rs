@@ -0,0 +1,175 @@
+use crate::api::ClickUpUser;
+use crate::ui::people_picker::{PeoplePicker, PeoplePickerEvent};
+use gpui::{Context, Entity, IntoElement, SharedString, Subscription, Window, div, prelude::*};
+use gpui_component::ActiveTheme as _;
+use gpui_component::Selectable;
+use gpui_component::Sizable;
+use gpui_component::button::Button;
+use gpui_component::checkbox::Checkbox;
+use gpui_component::input::{Input, InputEvent, InputState};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NewChatKind {
+    DirectMessage,
+    Channel,
+}
+
+#[derive(Clone, Debug)]
+pub enum NewChatRequest {
+    DirectMessage {
+        user_ids: Vec<u64>,
+        name: String,
+    },
+    Channel {
+        name: String,
+        private: bool,
+        user_ids: Vec<u64>,
+    },
+}
+
+pub struct NewChatForm {
+    kind: NewChatKind,
+    name_input: Entity<InputState>,
+    people: Entity<PeoplePicker>,
+    private: bool,
+    error: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl NewChatForm {
+    pub fn new(members: Vec<ClickUpUser>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Channel name"));
+        let people = cx.new(|cx| PeoplePicker::new(members, window, cx));
+
+        let subscriptions = vec![
+            cx.subscribe(&people, |this, _, _: &PeoplePickerEvent, cx| {
+                this.error = None;
+                cx.notify();
+            }),
+            cx.subscribe(&name_input, |this, _, event: &InputEvent, cx| {
+                if let InputEvent::Change = event {
+                    this.error = None;
+                    cx.notify();
+                }
+            }),
+        ];
+
+        Self {
+            kind: NewChatKind::DirectMessage,
+            name_input,
+            people,
+            private: false,
+            error: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    pub fn request(&mut self, cx: &mut Context<Self>) -> Option<NewChatRequest> {
+        let people = self.people.read(cx);
+        let user_ids = people.selected_ids();
+        let result = match self.kind {
+            NewChatKind::DirectMessage if user_ids.is_empty() => {
+                Err("Pick at least one person to message.")
+            }
+            NewChatKind::DirectMessage => Ok(NewChatRequest::DirectMessage {
+                name: people.selected_names().join(", "),
+                user_ids,
+            }),
+            NewChatKind::Channel => {
+                let name = self.name_input.read(cx).value().trim().to_string();
+                if name.is_empty() {
+                    Err("Give the channel a name.")
+                } else {
+                    Ok(NewChatRequest::Channel {
+                        name,
+                        private: self.private,
+                        user_ids,
+                    })
+                }
+            }
+        };
+
+        match result {
+            Ok(request) => Some(request),
+            Err(message) => {
+                self.error = Some(message.into());
+                cx.notify();
+                None
+            }
+        }
+    }
+
+    fn set_kind(&mut self, kind: NewChatKind, cx: &mut Context<Self>) {
+        self.kind = kind;
+        self.error = None;
+        cx.notify();
+    }
+
+    fn render_kind_picker(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .gap_2()
+            .child(
+                Button::new("new_chat_kind_dm")
+                    .small()
+                    .label("Direct message")
+                    .selected(self.kind == NewChatKind::DirectMessage)
+                    .on_click(cx.listener(|this, _ev, _window, cx| {
+                        this.set_kind(NewChatKind::DirectMessage, cx);
+                    })),
+            )
+            .child(
+                Button::new("new_chat_kind_channel")
+                    .small()
+                    .label("Channel")
+                    .selected(self.kind == NewChatKind::Channel)
+                    .on_click(cx.listener(|this, _ev, _window, cx| {
+                        this.set_kind(NewChatKind::Channel, cx);
+                    })),
+            )
+    }
+
+    fn render_channel_fields(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(Input::new(&self.name_input))
+            .child(
+                Checkbox::new("new_chat_private")
+                    .label("Private channel")
+                    .checked(self.private)
+                    .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                        this.private = *checked;
+                        cx.notify();
+                    })),
+            )
+    }
+}
+
+impl Render for NewChatForm {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(self.render_kind_picker(cx))
+            .when(self.kind == NewChatKind::Channel, |this| {
+                this.child(self.render_channel_fields(cx))
+            })
+            .child(
+                div()
+                    .text_xs()
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .text_color(cx.theme().muted_foreground)
+                    .child(match self.kind {
+                        NewChatKind::DirectMessage => "PEOPLE",
+                        NewChatKind::Channel => "MEMBERS (OPTIONAL)",
+                    }),
+            )
+            .child(self.people.clone())
+            .when_some(self.error.clone(), |this, error| {
+                this.child(div().text_sm().text_color(cx.theme().danger).child(error))
+            })
+    }
+}
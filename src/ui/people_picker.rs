@@ -0,0 +1,113 @@
+use crate::api::ClickUpUser;
+use gpui::{Context, Entity, IntoElement, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::ActiveTheme as _;
+use gpui_component::Sizable;
+use gpui_component::checkbox::Checkbox;
+use gpui_component::input::{Input, InputEvent, InputState};
+
+pub struct PeoplePicker {
+    search_input: Entity<InputState>,
+    members: Vec<ClickUpUser>,
+    selected: Vec<u64>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl PeoplePicker {
+    pub fn new(members: Vec<ClickUpUser>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let search_input = cx.new(|cx| InputState::new(window, cx).placeholder("Search people..."));
+
+        let subscriptions = vec![cx.subscribe(&search_input, |_, _, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                cx.notify();
+            }
+        })];
+
+        Self {
+            search_input,
+            members,
+            selected: Vec::new(),
+            _subscriptions: subscriptions,
+        }
+    }
+
+    pub fn selected_ids(&self) -> Vec<u64> {
+        self.selected.clone()
+    }
+
+    pub fn selected_names(&self) -> Vec<String> {
+        self.members
+            .iter()
+            .filter(|member| self.selected.contains(&member.id))
+            .map(|member| member.username.clone())
+            .collect()
+    }
+
+    fn toggle_member(&mut self, user_id: u64, cx: &mut Context<Self>) {
+        if let Some(ix) = self.selected.iter().position(|id| *id == user_id) {
+            self.selected.remove(ix);
+        } else {
+            self.selected.push(user_id);
+        }
+        cx.emit(PeoplePickerEvent::SelectionChanged);
+        cx.notify();
+    }
+}
+
+pub enum PeoplePickerEvent {
+    SelectionChanged,
+}
+
+impl gpui::EventEmitter<PeoplePickerEvent> for PeoplePicker {}
+
+impl Render for PeoplePicker {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let query = self.search_input.read(cx).value().trim().to_lowercase();
+        let members: Vec<(u64, SharedString, bool)> = self
+            .members
+            .iter()
+            .filter(|member| {
+                query.is_empty()
+                    || member.username.to_lowercase().contains(&query)
+                    || member.email.to_lowercase().contains(&query)
+            })
+            .map(|member| {
+                (
+                    member.id,
+                    member.username.clone().into(),
+                    self.selected.contains(&member.id),
+                )
+            })
+            .collect();
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(Input::new(&self.search_input).small())
+            .child(
+                div()
+                    .id("people_picker_list")
+                    .h(px(200.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .when(members.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("No people found"),
+                        )
+                    })
+                    .children(members.into_iter().map(|(user_id, username, checked)| {
+                        Checkbox::new(("people_picker_member", user_id))
+                            .label(username)
+                            .checked(checked)
+                            .on_click(cx.listener(move |this, _checked: &bool, _window, cx| {
+                                this.toggle_member(user_id, cx);
+                            }))
+                    })),
+            )
+    }
+}
@@ -0,0 +1,189 @@
+use crate::app::ClickLiteApp;
+use crate::search::{SearchHit, SearchScope};
+use crate::ui::stable_u64_hash;
+use gpui::{Context, HighlightStyle, IntoElement, StyledText, div, prelude::*, px};
+use gpui_component::ActiveTheme as _;
+use gpui_component::Sizable;
+use gpui_component::button::{Button, ButtonVariants as _};
+use gpui_component::input::{Escape, Input};
+
+pub fn render_search_panel(
+    app: &mut ClickLiteApp,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    div()
+        .id("search_panel")
+        .w(px(340.0))
+        .flex_none()
+        .flex()
+        .flex_col()
+        .bg(cx.theme().background)
+        .border_l_1()
+        .border_color(cx.theme().border)
+        .on_action(cx.listener(|this, _: &Escape, _window, cx| {
+            this.close_search(cx);
+        }))
+        .child(render_search_header(app, cx))
+        .child(render_search_results(app, cx))
+}
+
+fn render_search_header(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let scope = app.search_scope;
+    let has_channel = app.selected_channel.is_some();
+
+    div()
+        .flex_none()
+        .px_4()
+        .py_3()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_base()
+                        .font_weight(gpui::FontWeight::SEMIBOLD)
+                        .child("Search"),
+                )
+                .child(
+                    Button::new("close_search_button")
+                        .ghost()
+                        .xsmall()
+                        .label("Close")
+                        .on_click(cx.listener(|this, _ev, _window, cx| {
+                            this.close_search(cx);
+                        })),
+                ),
+        )
+        .child(Input::new(&app.search_input).small().w_full())
+        .child(
+            div()
+                .flex()
+                .gap_1()
+                .when(has_channel, |this| {
+                    this.child(
+                        Button::new("search_channel_scope")
+                            .xsmall()
+                            .when(scope == SearchScope::Channel, |this| this.primary())
+                            .when(scope != SearchScope::Channel, |this| this.ghost())
+                            .label("This chat")
+                            .on_click(cx.listener(|this, _ev, _window, cx| {
+                                this.set_search_scope(SearchScope::Channel, cx);
+                            })),
+                    )
+                })
+                .child(
+                    Button::new("search_workspace_scope")
+                        .xsmall()
+                        .when(scope == SearchScope::Workspace, |this| this.primary())
+                        .when(scope != SearchScope::Workspace, |this| this.ghost())
+                        .label("All chats")
+                        .on_click(cx.listener(|this, _ev, _window, cx| {
+                            this.set_search_scope(SearchScope::Workspace, cx);
+                        })),
+                ),
+        )
+}
+
+fn render_search_results(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let empty_message = if app.search_query.trim().is_empty() {
+        Some("Type to search loaded and cached messages.")
+    } else if app.search_results.is_empty() {
+        Some("No matching messages.")
+    } else {
+        None
+    };
+
+    let results: Vec<_> = app
+        .search_results
+        .iter()
+        .map(|hit| render_search_result(app, hit, cx).into_any_element())
+        .collect();
+
+    div()
+        .id("search_results")
+        .flex_1()
+        .min_h_0()
+        .overflow_y_scroll()
+        .p_2()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .when_some(empty_message, |this, message| {
+            this.child(
+                div()
+                    .p_2()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(message),
+            )
+        })
+        .children(results)
+}
+
+fn render_search_result(
+    app: &ClickLiteApp,
+    hit: &SearchHit,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    let channel_name = app
+        .channels
+        .iter()
+        .find(|channel| channel.id == hit.channel_id)
+        .map(|channel| format!("{}{}", channel.icon_prefix(), channel.display_name()));
+    let is_highlighted = app.highlighted_message_id.as_deref() == Some(hit.message_id.as_str());
+    let match_style = HighlightStyle {
+        background_color: Some(cx.theme().warning.opacity(0.35)),
+        font_weight: Some(gpui::FontWeight::SEMIBOLD),
+        ..Default::default()
+    };
+    let highlights: Vec<_> = hit
+        .highlights
+        .iter()
+        .map(|range| (range.clone(), match_style))
+        .collect();
+    let hit_clone = hit.clone();
+
+    div()
+        .id(("search_result", stable_u64_hash(&hit.message_id)))
+        .px_2()
+        .py_2()
+        .rounded_md()
+        .cursor_pointer()
+        .when(is_highlighted, |this| this.bg(cx.theme().accent))
+        .hover(|this| this.bg(cx.theme().accent))
+        .flex()
+        .flex_col()
+        .gap_1()
+        .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.open_search_result(hit_clone.clone(), cx);
+        }))
+        .child(
+            div()
+                .flex()
+                .gap_1()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .when(app.search_scope == SearchScope::Workspace, |this| {
+                    this.when_some(channel_name, |this, name| {
+                        this.child(div().truncate().child(format!("{name} ·")))
+                    })
+                })
+                .child(
+                    div()
+                        .font_weight(gpui::FontWeight::SEMIBOLD)
+                        .child(hit.author.clone()),
+                ),
+        )
+        .child(
+            div()
+                .text_sm()
+                .child(StyledText::new(hit.snippet.clone()).with_highlights(highlights)),
+        )
+}
@@ -1,11 +1,13 @@
+use crate::api::MESSAGES_PAGE_SIZE;
 use crate::app::ClickLiteApp;
 use crate::ui::stable_u64_hash;
-use gpui::{Context, IntoElement, img, div, prelude::*, px};
+use gpui::{Context, IntoElement, div, img, prelude::*, px};
 use gpui_component::ActiveTheme as _;
 use gpui_component::Selectable;
 use gpui_component::Sizable;
 use gpui_component::avatar::Avatar;
 use gpui_component::button::{Button, ButtonVariants as _};
+use gpui_component::menu::{DropdownMenu as _, PopupMenuItem};
 use gpui_component::skeleton::Skeleton;
 
 pub fn render_sidebar(app: &mut ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
@@ -18,13 +20,13 @@ pub fn render_sidebar(app: &mut ClickLiteApp, cx: &mut Context<ClickLiteApp>) ->
         .bg(cx.theme().background)
         .border_r_1()
         .border_color(cx.theme().border)
-        .child(render_sidebar_header(cx))
-        .child(render_channels_header(cx))
+        .child(render_sidebar_header(app, cx))
+        .child(render_channels_header(app, cx))
         .child(render_channel_list(app, cx))
         .child(render_sidebar_footer(app, cx))
 }
 
-fn render_sidebar_header(cx: &Context<ClickLiteApp>) -> impl IntoElement {
+fn render_sidebar_header(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
     div()
         .h(px(56.0))
         .flex_none()
@@ -34,6 +36,7 @@ fn render_sidebar_header(cx: &Context<ClickLiteApp>) -> impl IntoElement {
         .border_color(cx.theme().border)
         .flex()
         .items_center()
+        .justify_between()
         .gap_2()
         .child(
             div()
@@ -41,21 +44,116 @@ fn render_sidebar_header(cx: &Context<ClickLiteApp>) -> impl IntoElement {
                 .font_weight(gpui::FontWeight::SEMIBOLD)
                 .child("ClickLite"),
         )
+        .when(!app.workspaces.is_empty(), |this| {
+            this.child(render_workspace_picker(app, cx))
+        })
 }
 
-fn render_channels_header(cx: &Context<ClickLiteApp>) -> impl IntoElement {
+fn render_workspace_picker(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let app_entity = cx.entity();
+    let workspaces: Vec<_> = app
+        .workspaces
+        .iter()
+        .map(|workspace| (workspace.id, workspace.name.clone()))
+        .collect();
+    let selected_id = app.team_id;
+    let label = app
+        .selected_workspace()
+        .map(|workspace| workspace.name.clone())
+        .unwrap_or_else(|| "Workspace".to_string());
+
+    Button::new("workspace_picker")
+        .ghost()
+        .small()
+        .label(label)
+        .max_w(px(140.0))
+        .dropdown_menu(move |menu, _window, _cx| {
+            workspaces.iter().fold(menu, |menu, (workspace_id, name)| {
+                let app_entity = app_entity.clone();
+                let workspace_id = *workspace_id;
+                menu.item(
+                    PopupMenuItem::new(name.clone())
+                        .checked(selected_id == Some(workspace_id))
+                        .on_click(move |_ev, _window, cx| {
+                            app_entity.update(cx, |this, cx| {
+                                this.switch_workspace(workspace_id, cx);
+                            });
+                        }),
+                )
+            })
+        })
+}
+
+fn render_channels_header(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let unread_channels = app.unread_channel_count();
+
     div()
         .px_3()
         .py_2()
-        .text_xs()
-        .font_weight(gpui::FontWeight::SEMIBOLD)
-        .text_color(cx.theme().muted_foreground)
-        .child("CHATS")
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_weight(gpui::FontWeight::SEMIBOLD)
+                        .text_color(cx.theme().muted_foreground)
+                        .child("CHATS"),
+                )
+                .when(unread_channels > 0, |this| {
+                    this.child(render_unread_badge(unread_channels.to_string(), cx))
+                }),
+        )
+        .when(app.user.is_some() && app.team_id.is_some(), |this| {
+            this.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .child(render_do_not_disturb_toggle(app, cx))
+                    .child(
+                        Button::new("new_chat_button")
+                            .ghost()
+                            .xsmall()
+                            .label("+")
+                            .loading(app.team_members_loading)
+                            .on_click(cx.listener(|this, _ev, _window, cx| {
+                                this.open_new_chat_dialog(cx);
+                            })),
+                    ),
+            )
+        })
+}
+
+fn render_do_not_disturb_toggle(
+    app: &ClickLiteApp,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    let do_not_disturb = app.do_not_disturb();
+
+    Button::new("do_not_disturb_button")
+        .ghost()
+        .xsmall()
+        .label(if do_not_disturb { "🔕" } else { "🔔" })
+        .selected(do_not_disturb)
+        .tooltip(if do_not_disturb {
+            "Do not disturb is on"
+        } else {
+            "Pause notifications"
+        })
+        .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.toggle_do_not_disturb(cx);
+        }))
 }
 
 fn render_channel_list(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
     let app_entity = cx.entity();
-    let channels = if app.channels_loading {
+    let channels = if app.channels_loading && app.channels.is_empty() {
         div()
             .flex()
             .flex_col()
@@ -83,6 +181,8 @@ fn render_channel_list(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> im
                     .as_ref()
                     .map(|chat| chat.id == channel.id)
                     .unwrap_or(false);
+                let is_unread = app.is_channel_unread(channel);
+                let unread_count = app.unread_count(&channel.id);
 
                 Button::new(("channel", element_id))
                     .ghost()
@@ -90,6 +190,25 @@ fn render_channel_list(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> im
                     .w_full()
                     .justify_start()
                     .label(format!("{}{}", channel_clone.icon_prefix(), display_name))
+                    .when(is_unread, |this| {
+                        this.font_weight(gpui::FontWeight::BOLD)
+                            .child(match unread_count {
+                                Some(count) => render_unread_badge(
+                                    if count >= MESSAGES_PAGE_SIZE {
+                                        format!("{MESSAGES_PAGE_SIZE}+")
+                                    } else {
+                                        count.to_string()
+                                    },
+                                    cx,
+                                )
+                                .into_any_element(),
+                                None => div()
+                                    .size(px(8.))
+                                    .rounded_full()
+                                    .bg(cx.theme().primary)
+                                    .into_any_element(),
+                            })
+                    })
                     .on_click({
                         let app_entity = app_entity.clone();
                         move |_ev, _window, cx| {
@@ -99,11 +218,24 @@ fn render_channel_list(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> im
                         }
                     })
             }))
+            .when(app.channels_loading_more, |this| {
+                this.child(
+                    div()
+                        .px_3()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("Loading more chats…"),
+                )
+            })
             .into_any_element()
     };
 
     div()
+        .id("channel_list")
         .flex_1()
+        .min_h_0()
+        .overflow_y_scroll()
         .px_2()
         .flex()
         .flex_col()
@@ -111,6 +243,17 @@ fn render_channel_list(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> im
         .child(channels)
 }
 
+fn render_unread_badge(label: String, cx: &Context<ClickLiteApp>) -> impl IntoElement {
+    div()
+        .px_1p5()
+        .rounded_full()
+        .bg(cx.theme().primary)
+        .text_color(cx.theme().primary_foreground)
+        .text_xs()
+        .font_weight(gpui::FontWeight::SEMIBOLD)
+        .child(label)
+}
+
 fn render_sidebar_footer(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
     div()
         .p_3()
@@ -121,8 +264,9 @@ fn render_sidebar_footer(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) ->
 }
 
 fn render_user_chip(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let show_sign_in = app.user.is_none() && !app.clickup_loading && !app.signing_in;
     let status_color = match app {
-        _ if app.clickup_loading => cx.theme().warning,
+        _ if app.clickup_loading || app.signing_in => cx.theme().warning,
         _ if app.user.is_some() => cx.theme().success,
         _ => cx.theme().danger,
     };
@@ -140,7 +284,11 @@ fn render_user_chip(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl
         .border_color(cx.theme().border.opacity(0.85))
         .cursor_pointer()
         .on_click(cx.listener(|this, _ev, _window, cx| {
-            this.fetch_clickup_user(cx);
+            if this.user.is_some() {
+                this.fetch_clickup_user(cx);
+            } else {
+                this.sign_in(cx);
+            }
         }))
         .child(
             div()
@@ -151,7 +299,33 @@ fn render_user_chip(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl
                 .child(render_user_avatar(app))
                 .child(render_user_info(app, cx)),
         )
-        .child(div().size(px(8.)).rounded_full().bg(status_color))
+        .when(app.user.is_some(), |this| {
+            this.child(
+                Button::new("sign_out_button")
+                    .ghost()
+                    .xsmall()
+                    .label("Sign out")
+                    .on_click(cx.listener(|this, _ev, _window, cx| {
+                        this.sign_out(cx);
+                    })),
+            )
+        })
+        .child(if show_sign_in {
+            Button::new("sign_in_button")
+                .primary()
+                .small()
+                .label("Sign in")
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                    this.sign_in(cx);
+                }))
+                .into_any_element()
+        } else {
+            div()
+                .size(px(8.))
+                .rounded_full()
+                .bg(status_color)
+                .into_any_element()
+        })
 }
 
 fn render_user_avatar(app: &ClickLiteApp) -> impl IntoElement {
@@ -190,9 +364,10 @@ fn render_user_info(app: &ClickLiteApp, cx: &Context<ClickLiteApp>) -> impl Into
                 .text_xs()
                 .text_color(cx.theme().muted_foreground)
                 .child(match app {
+                    _ if app.signing_in => "Waiting for browser…",
                     _ if app.clickup_loading => "Connecting…",
                     _ if app.user.is_some() => "Connected",
-                    _ => "Click to connect",
+                    _ => "Not signed in",
                 }),
         )
 }
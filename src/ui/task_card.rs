@@ -0,0 +1,131 @@
+use crate::api::{ChatMessage, ClickUpTask};
+use crate::app::ClickLiteApp;
+use crate::ui::stable_u64_hash;
+use gpui::{Context, Hsla, IntoElement, div, prelude::*, px};
+use gpui_component::avatar::{Avatar, AvatarGroup};
+use gpui_component::{ActiveTheme as _, Colorize as _, Sizable as _};
+
+const TASK_CARD_WIDTH: f32 = 320.0;
+
+pub(super) fn render_task_cards(
+    app: &ClickLiteApp,
+    msg: &ChatMessage,
+    cx: &Context<ClickLiteApp>,
+) -> Option<impl IntoElement> {
+    let tasks: Vec<&ClickUpTask> = app
+        .task_refs(msg)
+        .iter()
+        .filter_map(|task_ref| app.task_preview(task_ref))
+        .collect();
+    if tasks.is_empty() {
+        return None;
+    }
+
+    Some(
+        div().flex().flex_col().gap_1().children(
+            tasks
+                .into_iter()
+                .map(|task| render_task_card(&msg.id, task, cx)),
+        ),
+    )
+}
+
+fn render_task_card(
+    message_id: &str,
+    task: &ClickUpTask,
+    cx: &Context<ClickLiteApp>,
+) -> impl IntoElement {
+    let status_color = task
+        .status
+        .color
+        .as_deref()
+        .and_then(|color| Hsla::parse_hex(color).ok())
+        .unwrap_or(cx.theme().muted_foreground);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let url = task.url.clone();
+
+    div()
+        .id((
+            "task_card",
+            stable_u64_hash(&format!("{message_id}:{}", task.id)),
+        ))
+        .w(px(TASK_CARD_WIDTH))
+        .px_3()
+        .py_2()
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .bg(cx.theme().background)
+        .cursor_pointer()
+        .hover(|this| this.bg(cx.theme().accent.opacity(0.5)))
+        .on_click(move |_ev, _window, cx| cx.open_url(&url))
+        .flex()
+        .flex_col()
+        .gap_1()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .text_xs()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .px_1()
+                        .rounded_sm()
+                        .bg(status_color.opacity(0.2))
+                        .text_color(status_color)
+                        .child(div().size(px(6.)).rounded_full().bg(status_color))
+                        .child(task.status.status.to_uppercase()),
+                )
+                .child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(task.display_id().to_string()),
+                ),
+        )
+        .child(
+            div()
+                .text_sm()
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .text_color(cx.theme().foreground)
+                .line_clamp(2)
+                .child(task.name.clone()),
+        )
+        .when(
+            !task.assignees.is_empty() || task.due_date.is_some(),
+            |this| {
+                this.child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .gap_2()
+                        .text_xs()
+                        .child(
+                            AvatarGroup::new().xsmall().limit(4).children(
+                                task.assignees
+                                    .iter()
+                                    .map(|assignee| Avatar::new().name(assignee.display_name())),
+                            ),
+                        )
+                        .when_some(task.due_date_label(), |this, label| {
+                            this.child(
+                                div()
+                                    .text_color(if task.is_overdue(now) {
+                                        cx.theme().danger
+                                    } else {
+                                        cx.theme().muted_foreground
+                                    })
+                                    .child(label),
+                            )
+                        }),
+                )
+            },
+        )
+}
@@ -0,0 +1,201 @@
+use crate::api::ChatMessage;
+use crate::app::ClickLiteApp;
+use crate::ui::chat_area::{render_attachments, render_message_content};
+use crate::ui::stable_u64_hash;
+use crate::ui::task_card::render_task_cards;
+use gpui::{Context, IntoElement, Window, div, prelude::*, px};
+use gpui_component::ActiveTheme as _;
+use gpui_component::Disableable;
+use gpui_component::Sizable;
+use gpui_component::avatar::Avatar;
+use gpui_component::button::{Button, ButtonVariants as _};
+use gpui_component::input::Input;
+
+pub fn render_thread_panel(
+    app: &mut ClickLiteApp,
+    window: &mut Window,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    div()
+        .id("thread_panel")
+        .w(px(340.0))
+        .flex_none()
+        .flex()
+        .flex_col()
+        .bg(cx.theme().background)
+        .border_l_1()
+        .border_color(cx.theme().border)
+        .child(render_thread_header(cx))
+        .child(render_thread_messages(app, window, cx))
+        .child(render_thread_composer(app, cx))
+}
+
+fn render_thread_header(cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    div()
+        .h(px(56.0))
+        .flex_none()
+        .px_4()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .text_base()
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .child("Thread"),
+        )
+        .child(
+            Button::new("close_thread_button")
+                .ghost()
+                .xsmall()
+                .label("Close")
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                    this.close_thread(cx);
+                })),
+        )
+}
+
+fn render_thread_messages(
+    app: &ClickLiteApp,
+    window: &mut Window,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    let parent = app
+        .thread_parent
+        .as_ref()
+        .map(|parent| render_thread_message(app, parent, window, cx).into_any_element());
+    let replies_count = app.thread_replies.len();
+    let replies: Vec<_> = app
+        .thread_replies
+        .iter()
+        .map(|reply| render_thread_message(app, reply, window, cx).into_any_element())
+        .collect();
+
+    div()
+        .id("thread_messages")
+        .flex_1()
+        .min_h_0()
+        .overflow_y_scroll()
+        .track_scroll(&app.thread_scroll_handle)
+        .p_4()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .children(parent)
+        .child(
+            div()
+                .pb_1()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(match replies_count {
+                    _ if app.thread_loading => "Loading replies…".to_string(),
+                    0 => "No replies yet".to_string(),
+                    1 => "1 reply".to_string(),
+                    count => format!("{count} replies"),
+                }),
+        )
+        .children(replies)
+}
+
+fn render_thread_message(
+    app: &ClickLiteApp,
+    msg: &ChatMessage,
+    window: &mut Window,
+    cx: &mut Context<ClickLiteApp>,
+) -> impl IntoElement {
+    let username = msg.creator_name();
+    let msg_id = stable_u64_hash(&msg.id);
+
+    div()
+        .id(("thread_msg", msg_id))
+        .flex()
+        .gap_2()
+        .w_full()
+        .child(
+            Avatar::new()
+                .name(username.clone())
+                .with_size(gpui_component::Size::Small),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .font_weight(gpui::FontWeight::SEMIBOLD)
+                                .child(username),
+                        )
+                        .when(msg.is_edited(), |this| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child("(edited)"),
+                            )
+                        }),
+                )
+                .when(msg.has_text() || msg.attachments.is_empty(), |this| {
+                    this.child(render_message_content(
+                        msg_id,
+                        &msg.display_content(),
+                        false,
+                        window,
+                        cx,
+                    ))
+                })
+                .when(!msg.attachments.is_empty(), |this| {
+                    this.child(render_attachments(app, msg, false, cx))
+                })
+                .children(render_task_cards(app, msg, cx)),
+        )
+}
+
+fn render_thread_composer(app: &ClickLiteApp, cx: &mut Context<ClickLiteApp>) -> impl IntoElement {
+    let can_send = !app.thread_sending
+        && !app
+            .thread_input
+            .read(cx)
+            .unmask_value()
+            .as_ref()
+            .trim()
+            .is_empty();
+
+    div()
+        .id("thread_input")
+        .px_4()
+        .py_3()
+        .border_t_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .gap_2()
+        .child(
+            Input::new(&app.thread_input)
+                .disabled(app.thread_sending)
+                .w_full()
+                .flex_1(),
+        )
+        .child(
+            Button::new("send_reply_button")
+                .primary()
+                .label("Reply")
+                .h(px(38.0))
+                .disabled(!can_send)
+                .loading(app.thread_sending)
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                    this.send_reply(cx);
+                })),
+        )
+}